@@ -1,3 +1,12 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+
+use crate::error::Error;
+use crate::parsers::is_empty_or_multispace;
+use crate::render::{item_to_plain_text, plain_text, split_first_word, split_type_expression};
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DocComment<'a> {
@@ -6,6 +15,1129 @@ pub struct DocComment<'a> {
     pub block_tags: Vec<BlockTag<'a>>,
 }
 
+impl<'a> DocComment<'a> {
+    /// Returns an iterator over all the text of the doc comment in document order:
+    /// text segments and inline tag body lines of the description and of every block tag.
+    pub fn text_segments(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.description
+            .iter()
+            .flat_map(|description| description.body_items.iter())
+            .chain(self.block_tags.iter().flat_map(|tag| tag.body_items.iter()))
+            .flat_map(|item| match item {
+                BodyItem::TextSegment(s) => std::slice::from_ref(s).iter().copied(),
+                BodyItem::InlineTag(tag) => tag.body_lines.iter().copied(),
+                BodyItem::CodeSpan(code) => std::slice::from_ref(code).iter().copied(),
+                BodyItem::CodeBlock { lines, .. } => lines.iter().copied(),
+                BodyItem::Link { target, label } => {
+                    std::slice::from_ref(label.as_ref().unwrap_or(target))
+                        .iter()
+                        .copied()
+                }
+                BodyItem::InheritDoc => std::iter::Copied::default(),
+            })
+    }
+
+    /// Compares two doc comments ignoring differences in whitespace, e.g. in indentation,
+    /// trailing spaces or empty lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/**\n * Foo  bar.\n *\n * @param foo\n */").unwrap();
+    /// let reindented = parse("/**\n    *   Foo bar. \n    * @param   foo */").unwrap();
+    ///
+    /// assert!(doc.equals_ignoring_whitespace(&reindented));
+    /// assert_ne!(doc, reindented);
+    /// ```
+    #[must_use]
+    pub fn equals_ignoring_whitespace(&self, other: &DocComment<'_>) -> bool {
+        body_eq_ignoring_whitespace(
+            &self.description_or_empty().body_items,
+            &other.description_or_empty().body_items,
+        ) && self.block_tags.len() == other.block_tags.len()
+            && self.block_tags.iter().zip(&other.block_tags).all(|(a, b)| {
+                a.name == b.name && body_eq_ignoring_whitespace(&a.body_items, &b.body_items)
+            })
+    }
+
+    /// Compares two doc comments ignoring where the `@deprecated` tags are placed among
+    /// the other block tags. The relative order of the remaining tags still matters.
+    /// Tag positions are ignored, as they change along with the placement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/**\n * @deprecated\n * @param foo\n * @returns bar\n */").unwrap();
+    /// let reordered = parse("/**\n * @param foo\n * @returns bar\n * @deprecated\n */").unwrap();
+    ///
+    /// assert!(doc.structurally_equal_ignoring_deprecated_position(&reordered));
+    /// assert_ne!(doc, reordered);
+    /// ```
+    #[must_use]
+    pub fn structurally_equal_ignoring_deprecated_position(&self, other: &DocComment<'_>) -> bool {
+        fn deprecated_last<'d, 'a>(
+            tags: &'d [BlockTag<'a>],
+        ) -> impl Iterator<Item = &'d BlockTag<'a>> {
+            let (deprecated, rest): (Vec<_>, Vec<_>) =
+                tags.iter().partition(|tag| tag.name == "deprecated");
+            rest.into_iter().chain(deprecated)
+        }
+
+        self.description == other.description
+            && self.block_tags.len() == other.block_tags.len()
+            && deprecated_last(&self.block_tags)
+                .zip(deprecated_last(&other.block_tags))
+                .all(|(a, b)| a.name == b.name && a.body_items == b.body_items)
+    }
+
+    /// Returns the number of whitespace-separated words in the description,
+    /// counting the body text of inline tags as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** Returns the {@link Foo the foo}. */").unwrap();
+    /// assert_eq!(doc.count_description_words(), 5);
+    /// ```
+    #[must_use]
+    pub fn count_description_words(&self) -> usize {
+        self.description.as_ref().map_or(0, |description| {
+            plain_text(&description.body_items)
+                .split_whitespace()
+                .count()
+        })
+    }
+
+    /// Returns the block tag at `index`, or `None` if there are not enough tags.
+    #[must_use]
+    pub fn tag_at(&self, index: usize) -> Option<&BlockTag<'a>> {
+        self.block_tags.get(index)
+    }
+
+    /// Returns a mutable reference to the block tag at `index`,
+    /// or `None` if there are not enough tags.
+    pub fn tag_at_mut(&mut self, index: usize) -> Option<&mut BlockTag<'a>> {
+        self.block_tags.get_mut(index)
+    }
+
+    /// Appends `tags` to the block tags of the doc comment.
+    /// The `position` of every tag is updated to its new index.
+    #[must_use]
+    pub fn extend_tags<I: IntoIterator<Item = BlockTag<'a>>>(mut self, tags: I) -> Self {
+        self.block_tags.extend(tags);
+        self.renumber_tags();
+        self
+    }
+
+    /// Updates the `position` of every block tag to its index.
+    fn renumber_tags(&mut self) {
+        for (position, tag) in self.block_tags.iter_mut().enumerate() {
+            tag.position = position;
+        }
+    }
+
+    /// Returns a copy of the doc comment whose description starts with a `text` segment.
+    /// If the comment has no description, the new one consists of `text` only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    /// use doctor::ast::BodyItem;
+    ///
+    /// let doc = parse("/** Finds the user. */").unwrap();
+    /// let doc = doc.prepend_description_text("Deprecated. ");
+    ///
+    /// assert_eq!(
+    ///     doc.description.unwrap().body_items,
+    ///     vec![
+    ///         BodyItem::TextSegment("Deprecated. "),
+    ///         BodyItem::TextSegment("Finds the user. "),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn prepend_description_text(&self, text: &'a str) -> Self {
+        let mut body_items = vec![BodyItem::TextSegment(text)];
+        if let Some(description) = &self.description {
+            body_items.extend(description.body_items.iter().cloned());
+        }
+        Self {
+            description: Some(Description { body_items }),
+            block_tags: self.block_tags.clone(),
+        }
+    }
+
+    /// Partitions the block tags by name, returning the tags named `name`
+    /// and a copy of the doc comment with the remaining ones.
+    /// The `position` of the remaining tags is updated to their new index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** Description.\n * @param foo\n * @returns bar */").unwrap();
+    /// let (params, rest) = doc.take_tags_by_name("param");
+    ///
+    /// assert_eq!(params, vec![&doc.block_tags[0]]);
+    /// assert_eq!(rest.description, doc.description);
+    /// assert_eq!(rest.block_tags[0].name, "returns");
+    /// ```
+    #[must_use]
+    pub fn take_tags_by_name(&self, name: &str) -> (Vec<&BlockTag<'a>>, Self) {
+        let (taken, remaining): (Vec<_>, Vec<_>) =
+            self.block_tags.iter().partition(|tag| tag.name == name);
+        let block_tags = remaining
+            .into_iter()
+            .enumerate()
+            .map(|(position, tag)| BlockTag {
+                position,
+                ..tag.clone()
+            })
+            .collect();
+        (
+            taken,
+            Self {
+                description: self.description.clone(),
+                block_tags,
+            },
+        )
+    }
+
+    /// Splits the doc comment before the first block tag named `name`.
+    ///
+    /// The first part has the description and the tags preceding that tag, the second one
+    /// the tag itself and every following tag. If there's no such tag, the second part is empty.
+    /// The `position` of the tags in the second part is updated to their new index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** Description.\n * @since 1.0\n * @param foo\n * @returns bar */")
+    ///     .unwrap();
+    /// let (preamble, rest) = doc.split_at_tag("param");
+    ///
+    /// assert_eq!(preamble.description, doc.description);
+    /// assert_eq!(preamble.tag_names().collect::<Vec<_>>(), vec!["since"]);
+    /// assert_eq!(rest.description, None);
+    /// assert_eq!(rest.tag_names().collect::<Vec<_>>(), vec!["param", "returns"]);
+    /// assert_eq!(rest.block_tags[0].position, 0);
+    /// ```
+    #[must_use]
+    pub fn split_at_tag(&self, name: &str) -> (Self, Self) {
+        let index = self
+            .block_tags
+            .iter()
+            .position(|tag| tag.name == name)
+            .unwrap_or(self.block_tags.len());
+        let (before, after) = self.block_tags.split_at(index);
+        (
+            Self {
+                description: self.description.clone(),
+                block_tags: before.to_vec(),
+            },
+            Self {
+                description: None,
+                block_tags: after
+                    .iter()
+                    .enumerate()
+                    .map(|(position, tag)| BlockTag {
+                        position,
+                        ..tag.clone()
+                    })
+                    .collect(),
+            },
+        )
+    }
+
+    /// Returns a copy of the doc comment without the block tags whose body is empty,
+    /// see [`BlockTag::is_empty`].
+    /// The `position` of the remaining tags is updated to their new index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** Description.\n * @param foo bar\n * @returns\n * @throws {@link Err} */")
+    ///     .unwrap();
+    /// let stripped = doc.strip_empty_tags();
+    ///
+    /// assert_eq!(stripped.tag_names().collect::<Vec<_>>(), vec!["param", "throws"]);
+    /// assert_eq!(stripped.block_tags[1].position, 1);
+    /// ```
+    #[must_use]
+    pub fn strip_empty_tags(&self) -> Self {
+        Self {
+            description: self.description.clone(),
+            block_tags: self
+                .block_tags
+                .iter()
+                .filter(|tag| !tag.is_empty())
+                .enumerate()
+                .map(|(position, tag)| BlockTag {
+                    position,
+                    ..tag.clone()
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns an owned copy of the doc comment with `f` applied to every text segment
+    /// and every line of the inline tag bodies, e.g. to translate the comment.
+    ///
+    /// Tag names, code and links are kept unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    /// use doctor::ast::OwnedBodyItem;
+    ///
+    /// let doc = parse("/** @param foo the foo */").unwrap();
+    /// let owned = doc.map_text(str::to_uppercase);
+    ///
+    /// assert_eq!(owned.block_tags[0].name, "param");
+    /// assert_eq!(
+    ///     owned.block_tags[0].body_items,
+    ///     vec![OwnedBodyItem::TextSegment("FOO THE FOO ".to_owned())]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn map_text<F: Fn(&str) -> String>(&self, f: F) -> OwnedDocComment {
+        let map_items = |body_items: &[BodyItem<'_>]| -> Vec<OwnedBodyItem> {
+            body_items
+                .iter()
+                .map(|item| match item {
+                    BodyItem::TextSegment(s) => OwnedBodyItem::TextSegment(f(s)),
+                    BodyItem::InlineTag(tag) => OwnedBodyItem::InlineTag(OwnedInlineTag {
+                        name: tag.name.to_owned(),
+                        body_lines: tag.body_lines.iter().map(|line| f(line)).collect(),
+                    }),
+                    item => OwnedBodyItem::from(item),
+                })
+                .collect()
+        };
+
+        OwnedDocComment {
+            description: self
+                .description
+                .as_ref()
+                .map(|description| OwnedDescription {
+                    body_items: map_items(&description.body_items),
+                }),
+            block_tags: self
+                .block_tags
+                .iter()
+                .map(|tag| OwnedBlockTag {
+                    name: tag.name.to_owned(),
+                    position: tag.position,
+                    body_items: map_items(&tag.body_items),
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns an owned copy of the doc comment with every block tag replaced with the result
+    /// of `f`, e.g. to rename the tags. The description is kept unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    /// use doctor::ast::OwnedBlockTag;
+    ///
+    /// let doc = parse("/** Description.\n * @return foo */").unwrap();
+    /// let owned = doc.map_tags(|tag| OwnedBlockTag {
+    ///     name: tag.name.replace("return", "returns"),
+    ///     ..OwnedBlockTag::from(tag)
+    /// });
+    ///
+    /// assert_eq!(owned.description, doc.description.as_ref().map(Into::into));
+    /// assert_eq!(owned.block_tags[0].name, "returns");
+    /// ```
+    #[must_use]
+    pub fn map_tags<F: Fn(&BlockTag<'a>) -> OwnedBlockTag>(&self, f: F) -> OwnedDocComment {
+        OwnedDocComment {
+            description: self.description.as_ref().map(OwnedDescription::from),
+            block_tags: self.block_tags.iter().map(f).collect(),
+        }
+    }
+
+    /// Returns an owned copy of the doc comment with the description, if any, replaced with
+    /// the result of `f`. The block tags are kept unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    /// use doctor::ast::{OwnedBodyItem, OwnedDescription};
+    ///
+    /// let doc = parse("/** Description.\n * @param foo */").unwrap();
+    /// let owned = doc.map_description(|_| OwnedDescription {
+    ///     body_items: vec![OwnedBodyItem::TextSegment("Replaced.".to_owned())],
+    /// });
+    ///
+    /// assert_eq!(
+    ///     owned.description.unwrap().body_items,
+    ///     vec![OwnedBodyItem::TextSegment("Replaced.".to_owned())]
+    /// );
+    /// assert_eq!(owned.block_tags, doc.block_tags);
+    /// ```
+    #[must_use]
+    pub fn map_description<F: Fn(&Description<'a>) -> OwnedDescription>(
+        &self,
+        f: F,
+    ) -> OwnedDocComment {
+        OwnedDocComment {
+            description: self.description.as_ref().map(f),
+            block_tags: self.block_tags.iter().map(OwnedBlockTag::from).collect(),
+        }
+    }
+
+    /// Returns an owned copy of the doc comment with the block tags named after a key of `aliases`
+    /// renamed to the corresponding value, e.g. `arg` to `param`.
+    /// Other tags are copied unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** @arg foo\n * @exception Error\n * @since 1.0 */").unwrap();
+    /// let aliases: HashMap<_, _> = vec![("arg", "param"), ("exception", "throws")]
+    ///     .into_iter()
+    ///     .collect();
+    /// let owned = doc.apply_tag_aliases(&aliases);
+    ///
+    /// let names: Vec<_> = owned.block_tags.iter().map(|tag| tag.name.as_str()).collect();
+    /// assert_eq!(names, vec!["param", "throws", "since"]);
+    /// ```
+    #[must_use]
+    pub fn apply_tag_aliases(&self, aliases: &HashMap<&str, &str>) -> OwnedDocComment {
+        self.map_tags(|tag| OwnedBlockTag {
+            name: (*aliases.get(tag.name).unwrap_or(&tag.name)).to_owned(),
+            ..OwnedBlockTag::from(tag)
+        })
+    }
+
+    /// Returns `true` if the description contains an inline tag named `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** Returns the {@link Foo}.\n * @param bar {@code bar} */").unwrap();
+    /// assert!(doc.has_inline_tag_in_description("link"));
+    /// assert!(!doc.has_inline_tag_in_description("code"));
+    /// ```
+    #[must_use]
+    pub fn has_inline_tag_in_description(&self, name: &str) -> bool {
+        self.description
+            .as_ref()
+            .is_some_and(|description| description.inline_tags().any(|tag| tag.name == name))
+    }
+
+    /// Splits the description into its summary, i.e. the first sentence or paragraph,
+    /// and the details following it, both rendered as plain text.
+    ///
+    /// The summary ends at the first `.`, `!` or `?` followed by whitespace, or at the first
+    /// empty line. Parts without any text are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/**\n * Returns the {@link Foo}. Fails if\n * it's missing.\n */").unwrap();
+    /// assert_eq!(
+    ///     doc.summary_and_details(),
+    ///     (Some("Returns the Foo.".to_owned()), Some("Fails if\nit's missing.".to_owned()))
+    /// );
+    ///
+    /// let doc = parse("/**\n * Summary without a period\n *\n * Details.\n */").unwrap();
+    /// assert_eq!(
+    ///     doc.summary_and_details(),
+    ///     (Some("Summary without a period".to_owned()), Some("Details.".to_owned()))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn summary_and_details(&self) -> (Option<String>, Option<String>) {
+        let non_empty = |text: String| Some(text).filter(|text| !text.is_empty());
+        let body_items = match &self.description {
+            Some(description) => description.body_items.as_slice(),
+            None => return (None, None),
+        };
+
+        for (index, item) in body_items.iter().enumerate() {
+            let BodyItem::TextSegment(s) = item else {
+                continue;
+            };
+            if s.trim().is_empty() {
+                // A blank line, unless it's the line ending after an inline tag.
+                let ends_previous_line = index
+                    .checked_sub(1)
+                    .is_some_and(|previous| {
+                        matches!(body_items[previous], BodyItem::TextSegment(p) if p.ends_with('\n'))
+                    });
+                if ends_previous_line && s.contains('\n') {
+                    return (
+                        non_empty(plain_text(&body_items[..index])),
+                        non_empty(plain_text(&body_items[index + 1..])),
+                    );
+                }
+                continue;
+            }
+
+            let is_last = index == body_items.len() - 1;
+            let sentence_end = s.char_indices().find_map(|(position, ch)| {
+                let end = position + ch.len_utf8();
+                let at_boundary = s[end..].chars().next().map_or(is_last, char::is_whitespace);
+                (matches!(ch, '.' | '!' | '?') && at_boundary).then_some(end)
+            });
+            if let Some(end) = sentence_end {
+                let mut summary = body_items[..index].to_vec();
+                summary.push(BodyItem::TextSegment(&s[..end]));
+                let mut details = vec![BodyItem::TextSegment(&s[end..])];
+                details.extend_from_slice(&body_items[index + 1..]);
+                return (
+                    non_empty(plain_text(&summary)),
+                    non_empty(plain_text(&details)),
+                );
+            }
+        }
+        (non_empty(plain_text(body_items)), None)
+    }
+
+    /// Returns `true` if the first non-blank item of the description is an inline tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** {@link Foo} is returned */").unwrap();
+    /// assert!(doc.description_starts_with_inline_tag());
+    ///
+    /// let doc = parse("/** Returns {@link Foo} */").unwrap();
+    /// assert!(!doc.description_starts_with_inline_tag());
+    /// ```
+    #[must_use]
+    pub fn description_starts_with_inline_tag(&self) -> bool {
+        self.description.as_ref().is_some_and(|description| {
+            matches!(
+                description.body_items.iter().find(|item| {
+                    !matches!(item, BodyItem::TextSegment(text) if text.trim().is_empty())
+                }),
+                Some(BodyItem::InlineTag(_))
+            )
+        })
+    }
+
+    /// Returns an iterator over the names of the block tags, in document order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** @param foo\n * @returns bar */").unwrap();
+    /// assert_eq!(doc.tag_names().collect::<Vec<_>>(), vec!["param", "returns"]);
+    /// ```
+    pub fn tag_names(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.block_tags.iter().map(|tag| tag.name)
+    }
+
+    /// Returns an iterator over the names of the inline tags in the description,
+    /// in document order.
+    pub fn inline_tag_names_in_description(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.description
+            .iter()
+            .flat_map(Description::inline_tags)
+            .map(|tag| tag.name)
+    }
+
+    /// Returns an iterator over the inline tags of the description and the block tags,
+    /// in document order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** See {@link Foo}.\n * @param foo the {@code foo} */").unwrap();
+    /// let names: Vec<_> = doc.all_inline_tags().map(|tag| tag.name).collect();
+    /// assert_eq!(names, vec!["link", "code"]);
+    /// ```
+    pub fn all_inline_tags(&self) -> impl Iterator<Item = &InlineTag<'a>> {
+        self.description
+            .iter()
+            .flat_map(Description::inline_tags)
+            .chain(self.block_tags.iter().flat_map(BlockTag::inline_tags))
+    }
+
+    /// Returns the number of block tags.
+    #[inline]
+    #[must_use]
+    pub fn block_tag_count(&self) -> usize {
+        self.block_tags.len()
+    }
+
+    /// Returns the number of inline tags in the description and the block tags,
+    /// see [`DocComment::all_inline_tags`].
+    #[inline]
+    #[must_use]
+    pub fn inline_tag_count(&self) -> usize {
+        self.all_inline_tags().count()
+    }
+
+    /// Returns the number of characters of the description and the block tag bodies,
+    /// see [`BodyItem::char_count`]. Comment delimiters, line leadings and tag names
+    /// aren't counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/**\n * See {@link Foo}.\n * @param bar\n */").unwrap();
+    /// assert_eq!(doc.total_char_count(), "See ".len() + "Foo".len() + ".\n".len() + "bar\n".len());
+    /// ```
+    #[must_use]
+    pub fn total_char_count(&self) -> usize {
+        self.description
+            .iter()
+            .flat_map(|description| description.body_items.iter())
+            .chain(self.block_tags.iter().flat_map(|tag| tag.body_items.iter()))
+            .map(BodyItem::char_count)
+            .sum()
+    }
+
+    /// Returns the total number of body items of the description and the block tags,
+    /// e.g. as a measure of the complexity of the comment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** See {@link Foo}.\n * @param foo\n * @returns */").unwrap();
+    /// assert_eq!(doc.body_item_count(), 4);
+    /// ```
+    #[must_use]
+    pub fn body_item_count(&self) -> usize {
+        self.description
+            .iter()
+            .map(|description| description.body_items.len())
+            .chain(self.block_tags.iter().map(|tag| tag.body_items.len()))
+            .sum()
+    }
+
+    /// Returns the longest block tag name, e.g. to align the tag bodies in a column.
+    /// If several names are equally long, the first one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** @param foo\n * @returns bar\n * @throws Error */").unwrap();
+    /// assert_eq!(doc.longest_block_tag_name(), Some("returns"));
+    /// ```
+    #[must_use]
+    pub fn longest_block_tag_name(&self) -> Option<&'a str> {
+        self.block_tags
+            .iter()
+            .rev()
+            .max_by_key(|tag| tag.name.len())
+            .map(|tag| tag.name)
+    }
+
+    /// Consumes the doc comment, returning its block tags.
+    #[must_use]
+    pub fn into_block_tags(self) -> Vec<BlockTag<'a>> {
+        self.block_tags
+    }
+
+    /// Consumes the doc comment, returning its description.
+    #[must_use]
+    pub fn into_description(self) -> Option<Description<'a>> {
+        self.description
+    }
+
+    /// Consumes the doc comment, returning its description and block tags.
+    #[must_use]
+    pub fn into_parts(self) -> (Option<Description<'a>>, Vec<BlockTag<'a>>) {
+        (self.description, self.block_tags)
+    }
+
+    /// Returns the description, or an empty one if the comment has none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** @param foo */").unwrap();
+    /// assert!(doc.description_or_empty().body_items.is_empty());
+    /// ```
+    #[must_use]
+    pub fn description_or_empty(&self) -> &Description<'a> {
+        static EMPTY: Description<'static> = Description {
+            body_items: Vec::new(),
+        };
+        self.description.as_ref().unwrap_or(&EMPTY)
+    }
+
+    /// Groups the block tags named in `names` by their name.
+    ///
+    /// Every name in `names` is present in the returned map, mapped to the (possibly empty)
+    /// list of matching tags in document order. Tags with other names are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** @param a\n * @param b\n * @since 1.0 */").unwrap();
+    /// let tags = doc.tags_of_type(&["param", "returns"]);
+    ///
+    /// assert_eq!(tags.len(), 2);
+    /// assert_eq!(tags["param"].len(), 2);
+    /// assert!(tags["returns"].is_empty());
+    /// ```
+    #[must_use]
+    pub fn tags_of_type<'b, 'n>(
+        &'b self,
+        names: &[&'n str],
+    ) -> HashMap<&'n str, Vec<&'b BlockTag<'a>>> {
+        let mut tags: HashMap<_, Vec<_>> = names.iter().map(|name| (*name, vec![])).collect();
+        for tag in &self.block_tags {
+            if let Some(matching) = tags.get_mut(tag.name) {
+                matching.push(tag);
+            }
+        }
+        tags
+    }
+
+    /// Checks that the comment has a block tag for each of `names`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** @param a\n * @since 1.0 */").unwrap();
+    ///
+    /// assert_eq!(doc.requires_tags(&["param", "since"]), Ok(()));
+    /// assert_eq!(doc.requires_tags(&["returns", "param", "throws"]), Err(vec!["returns", "throws"]));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the names without a matching block tag, in the order of `names`.
+    pub fn requires_tags<'n>(&self, names: &[&'n str]) -> Result<(), Vec<&'n str>> {
+        let missing: Vec<_> = names
+            .iter()
+            .copied()
+            .filter(|name| !self.block_tags.iter().any(|tag| tag.name == *name))
+            .collect();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
+    /// Groups the `@param` tags by the parameter they document.
+    ///
+    /// A tag documenting a property in dotted notation, e.g. `@param options.timeout`,
+    /// is added to the properties of the group of its prefix (`options`),
+    /// any other tag provides the description of its own group.
+    /// Groups are returned in the order they are first mentioned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse(r#"/**
+    ///     * @param options the request options
+    ///     * @param options.timeout the timeout in ms
+    ///     * @param callback
+    ///     */"#).unwrap();
+    /// let groups = doc.group_params();
+    ///
+    /// assert_eq!(groups[0].name, "options");
+    /// assert_eq!(groups[0].description.as_deref(), Some("the request options"));
+    /// assert_eq!(groups[0].properties, vec![&doc.block_tags[1]]);
+    /// assert_eq!(groups[1].name, "callback");
+    /// assert_eq!(groups[1].description, None);
+    /// ```
+    #[must_use]
+    pub fn group_params(&self) -> Vec<ParamGroup<'_>> {
+        let mut groups: Vec<ParamGroup<'_>> = vec![];
+        for tag in self.block_tags.iter().filter(|tag| tag.name == "param") {
+            let (name, description) = split_first_word(&tag.body_items);
+            let (group_name, is_property) = name
+                .split_once('.')
+                .map_or((name, false), |(prefix, _)| (prefix, true));
+
+            let index = groups
+                .iter()
+                .position(|group| group.name == group_name)
+                .unwrap_or_else(|| {
+                    groups.push(ParamGroup {
+                        name: group_name,
+                        description: None,
+                        properties: vec![],
+                    });
+                    groups.len() - 1
+                });
+            let group = &mut groups[index];
+            if is_property {
+                group.properties.push(tag);
+            } else if !description.is_empty() {
+                group.description = Some(description);
+            }
+        }
+        groups
+    }
+
+    /// Returns the first `@param` tag, if any.
+    #[must_use]
+    pub fn first_param(&self) -> Option<&BlockTag<'a>> {
+        self.block_tags.iter().find(|tag| tag.name == "param")
+    }
+
+    /// Returns the last `@param` tag, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** @param a\n * @param b\n * @returns c */").unwrap();
+    ///
+    /// assert_eq!(doc.first_param(), Some(&doc.block_tags[0]));
+    /// assert_eq!(doc.last_param(), Some(&doc.block_tags[1]));
+    /// ```
+    #[must_use]
+    pub fn last_param(&self) -> Option<&BlockTag<'a>> {
+        self.block_tags.iter().rfind(|tag| tag.name == "param")
+    }
+
+    /// Returns the names of the parameters documented by the `@param` tags, in document order.
+    ///
+    /// The name is the first word of the tag body, after the `{Type}` expression if the comment
+    /// was parsed with
+    /// [`ParseConfig::parse_type_expressions`](crate::config::ParseConfig::parse_type_expressions)
+    /// enabled. Tags without a name are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** @param foo the foo\n * @param\n * @param bar */").unwrap();
+    /// assert_eq!(doc.param_names(), vec!["foo", "bar"]);
+    /// ```
+    #[must_use]
+    pub fn param_names(&self) -> Vec<&'a str> {
+        self.block_tags
+            .iter()
+            .filter(|tag| tag.name == "param")
+            .map(|tag| split_first_word(split_type_expression(&tag.body_items).1).0)
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    /// Returns the `@prop` tags in the `@prop {Type} name - description` form.
+    ///
+    /// The type expression is only recognized when the comment was parsed with
+    /// [`ParseConfig::parse_prop_tags`](crate::config::ParseConfig::parse_prop_tags) enabled.
+    /// If it's missing, `prop_type` is `None` and `name` is the first word of the body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse_with_config;
+    /// use doctor::ast::PropTag;
+    /// use doctor::config::ParseConfig;
+    ///
+    /// let config = ParseConfig {
+    ///     parse_prop_tags: true,
+    ///     ..ParseConfig::default()
+    /// };
+    /// let doc = parse_with_config("/** @prop {string} title - The title. */", config).unwrap();
+    ///
+    /// assert_eq!(
+    ///     doc.prop_tags(),
+    ///     vec![PropTag {
+    ///         prop_type: Some("string"),
+    ///         name: "title",
+    ///         description: Some("The title.".to_owned()),
+    ///     }]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn prop_tags(&self) -> Vec<PropTag<'a>> {
+        self.block_tags
+            .iter()
+            .filter(|tag| tag.name == "prop")
+            .map(|tag| {
+                let (prop_type, rest) = split_type_expression(&tag.body_items);
+                let (name, description) = split_first_word(rest);
+                let description = description.strip_prefix('-').unwrap_or(&description).trim();
+                PropTag {
+                    prop_type,
+                    name,
+                    description: Some(description.to_owned()).filter(|d| !d.is_empty()),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the trimmed text of the first `@deprecated` tag, or `None` if there's no such tag.
+    ///
+    /// The text is borrowed from the input when the body is a single text segment.
+    /// A body spanning several lines has one text segment per line, separated by the line
+    /// leadings in the input, so it can't be borrowed as a single `&str`: such bodies,
+    /// as well as bodies with inline tags, are rendered into an owned plain text string instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** @deprecated Use {@link bar} instead */").unwrap();
+    /// assert_eq!(doc.deprecation_message().as_deref(), Some("Use bar instead"));
+    ///
+    /// let doc = parse("/** @deprecated */").unwrap();
+    /// assert_eq!(doc.deprecation_message().as_deref(), Some(""));
+    ///
+    /// let doc = parse("/** Description */").unwrap();
+    /// assert_eq!(doc.deprecation_message(), None);
+    /// ```
+    #[must_use]
+    pub fn deprecation_message(&self) -> Option<Cow<'a, str>> {
+        let tag = self
+            .block_tags
+            .iter()
+            .find(|tag| tag.name == "deprecated")?;
+        Some(match tag.body_items.as_slice() {
+            [] => Cow::Borrowed(""),
+            [BodyItem::TextSegment(s)] => Cow::Borrowed(s.trim()),
+            body_items => Cow::Owned(plain_text(body_items)),
+        })
+    }
+
+    /// Returns the `@example` tags, with their title if their body starts with
+    /// a `<caption>title</caption>` line.
+    ///
+    /// A caption followed by code on the same line is only recognized when the comment was
+    /// parsed with
+    /// [`ParseConfig::parse_example_titles`](crate::config::ParseConfig::parse_example_titles)
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse(r#"/**
+    ///     * @example <caption>Basic usage</caption>
+    ///     * foo(1)
+    ///     * @example foo(2)
+    ///     */"#).unwrap();
+    /// let examples = doc.example_tags();
+    ///
+    /// assert_eq!(examples[0].title, Some("Basic usage"));
+    /// assert_eq!(examples[0].code, "foo(1)");
+    /// assert_eq!(examples[1].title, None);
+    /// assert_eq!(examples[1].code, "foo(2)");
+    /// ```
+    #[must_use]
+    pub fn example_tags(&self) -> Vec<ExampleTag<'a>> {
+        self.block_tags
+            .iter()
+            .filter(|tag| tag.name == "example")
+            .map(|tag| {
+                let (title, rest) = match tag.body_items.split_first() {
+                    Some((BodyItem::TextSegment(s), rest)) => s
+                        .trim()
+                        .strip_prefix("<caption>")
+                        .and_then(|s| s.strip_suffix("</caption>"))
+                        .map_or((None, tag.body_items.as_slice()), |title| {
+                            (Some(title.trim()), rest)
+                        }),
+                    _ => (None, tag.body_items.as_slice()),
+                };
+                let code = match rest {
+                    [] => Cow::Borrowed(""),
+                    [BodyItem::TextSegment(s)] => Cow::Borrowed(s.trim()),
+                    body_items => Cow::Owned(plain_text(body_items)),
+                };
+                ExampleTag { title, code }
+            })
+            .collect()
+    }
+
+    /// Returns the references of the `@see` tags.
+    ///
+    /// A tag whose body starts with `http://` or `https://` refers to a [`SeeRef::Url`],
+    /// one containing a `{@link}` tag to a [`SeeRef::Link`] with the link target,
+    /// and any other tag to
+    /// a [`SeeRef::Text`] with the text of its first segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    /// use doctor::ast::SeeRef;
+    ///
+    /// let doc = parse(r#"/**
+    ///     * @see https://example.com the docs
+    ///     * @see {@link Foo#bar}
+    ///     * @see Bar
+    ///     */"#).unwrap();
+    ///
+    /// assert_eq!(
+    ///     doc.see_tags(),
+    ///     vec![
+    ///         SeeRef::Url("https://example.com"),
+    ///         SeeRef::Link("Foo#bar"),
+    ///         SeeRef::Text("Bar"),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn see_tags(&self) -> Vec<SeeRef<'a>> {
+        self.block_tags
+            .iter()
+            .filter(|tag| tag.name == "see")
+            .map(|tag| {
+                if let Some(BodyItem::TextSegment(s)) = tag.body_items.first() {
+                    let s = s.trim_start();
+                    if s.starts_with("http://") || s.starts_with("https://") {
+                        return SeeRef::Url(s.split_whitespace().next().unwrap_or(s));
+                    }
+                }
+
+                tag.body_items
+                    .iter()
+                    .find_map(|item| match item {
+                        BodyItem::InlineTag(tag) if tag.name == "link" => Some(SeeRef::Link(
+                            tag.body_lines
+                                .first()
+                                .and_then(|line| line.split_whitespace().next())
+                                .unwrap_or(""),
+                        )),
+                        BodyItem::Link { target, .. } => Some(SeeRef::Link(target)),
+                        _ => None,
+                    })
+                    .unwrap_or_else(|| {
+                        SeeRef::Text(
+                            tag.body_items
+                                .iter()
+                                .find_map(|item| match item {
+                                    BodyItem::TextSegment(s) => Some(s.trim()),
+                                    _ => None,
+                                })
+                                .unwrap_or(""),
+                        )
+                    })
+            })
+            .collect()
+    }
+}
+
+impl<'a> From<Vec<BlockTag<'a>>> for DocComment<'a> {
+    /// Creates a doc comment without a description consisting of `block_tags`.
+    fn from(block_tags: Vec<BlockTag<'a>>) -> Self {
+        Self {
+            description: None,
+            block_tags,
+        }
+    }
+}
+
+impl<'a> Extend<BlockTag<'a>> for DocComment<'a> {
+    /// Appends `tags` to the block tags of the doc comment, like [`DocComment::extend_tags`].
+    /// The `position` of every tag is updated to its new index.
+    fn extend<I: IntoIterator<Item = BlockTag<'a>>>(&mut self, tags: I) {
+        self.block_tags.extend(tags);
+        self.renumber_tags();
+    }
+}
+
+impl<'a> From<Description<'a>> for DocComment<'a> {
+    /// Creates a doc comment without block tags consisting of `description`.
+    fn from(description: Description<'a>) -> Self {
+        Self {
+            description: Some(description),
+            block_tags: vec![],
+        }
+    }
+}
+
+/// Reference of a `@see` tag.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum SeeRef<'a> {
+    /// A URL, e.g. `@see https://example.com`.
+    Url(&'a str),
+    /// Target of a `{@link}` tag, e.g. `@see {@link Foo}`.
+    Link(&'a str),
+    /// Any other reference, e.g. `@see Foo`.
+    Text(&'a str),
+}
+
+/// A `@prop {Type} name - description` tag.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct PropTag<'a> {
+    /// Type of the property, without the braces.
+    pub prop_type: Option<&'a str>,
+    /// Name of the property.
+    pub name: &'a str,
+    /// Description of the property, without the separating dash.
+    pub description: Option<String>,
+}
+
+/// An `@example` tag with an optional `<caption>title</caption>` first line.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ExampleTag<'a> {
+    /// Title of the example, without the `<caption>` element.
+    pub title: Option<&'a str>,
+    /// Code of the example, trimmed.
+    ///
+    /// Borrowed from the input if it consists of a single text segment.
+    /// Multi-line code has one text segment per line, separated by the line leadings
+    /// in the input, so it can't be borrowed as a single `&str` and is owned instead.
+    pub code: Cow<'a, str>,
+}
+
+/// A parameter documented by `@param` tags along with the tags documenting its properties.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ParamGroup<'a> {
+    /// Name of the parameter.
+    pub name: &'a str,
+    /// Description from the `@param` tag documenting the parameter itself, if any.
+    pub description: Option<String>,
+    /// `@param` tags documenting properties of the parameter in dotted notation.
+    pub properties: Vec<&'a BlockTag<'a>>,
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Description<'a> {
@@ -13,18 +1145,427 @@ pub struct Description<'a> {
     pub body_items: Vec<BodyItem<'a>>,
 }
 
+impl<'a> Description<'a> {
+    /// Creates a description consisting of a single text segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::ast::{Description, InlineTag};
+    ///
+    /// let description = Description::from_text("See ")
+    ///     .append_inline_tag(InlineTag::new("link", "Foo"))
+    ///     .append_text(".");
+    ///
+    /// assert_eq!(description.body_items.len(), 3);
+    /// ```
+    #[must_use]
+    pub fn from_text(text: &'a str) -> Self {
+        Self {
+            body_items: vec![BodyItem::TextSegment(text)],
+        }
+    }
+
+    /// Appends a text segment to the description.
+    #[must_use]
+    pub fn append_text(mut self, text: &'a str) -> Self {
+        self.body_items.push(BodyItem::TextSegment(text));
+        self
+    }
+
+    /// Appends an inline tag to the description.
+    #[must_use]
+    pub fn append_inline_tag(mut self, tag: InlineTag<'a>) -> Self {
+        self.body_items.push(BodyItem::InlineTag(tag));
+        self
+    }
+
+    /// Returns the body items of the description.
+    #[must_use]
+    pub fn body_items_ref(&self) -> &[BodyItem<'a>] {
+        &self.body_items
+    }
+
+    /// Consumes the description, returning its body items.
+    #[must_use]
+    pub fn body_items_owned(self) -> Vec<BodyItem<'a>> {
+        self.body_items
+    }
+
+    /// Returns an iterator over the inline tags of the description.
+    pub fn inline_tags(&self) -> impl Iterator<Item = &InlineTag<'a>> {
+        self.body_items.iter().filter_map(|item| match item {
+            BodyItem::InlineTag(tag) => Some(tag),
+            BodyItem::TextSegment(_)
+            | BodyItem::CodeSpan(_)
+            | BodyItem::CodeBlock { .. }
+            | BodyItem::Link { .. }
+            | BodyItem::InheritDoc => None,
+        })
+    }
+
+    /// Returns the number of sentences in the description, i.e. the number of words ending
+    /// with `.`, `!` or `?`. Inline tags count as their body text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** Returns the {@link Foo}. Fails if missing! */").unwrap();
+    /// assert_eq!(doc.description.unwrap().sentence_count(), 2);
+    /// ```
+    #[must_use]
+    pub fn sentence_count(&self) -> usize {
+        plain_text(&self.body_items)
+            .split_whitespace()
+            .filter(|word| word.ends_with(&['.', '!', '?'][..]))
+            .count()
+    }
+
+    /// Returns the indentation of the first text segment of the description,
+    /// counting each tab as 4 spaces.
+    ///
+    /// See [`Description::leading_spaces_with_tab_width`] for details.
+    #[must_use]
+    pub fn leading_spaces(&self) -> usize {
+        self.leading_spaces_with_tab_width(4)
+    }
+
+    /// Returns the indentation of the first text segment of the description,
+    /// counting each tab as `tab_width` spaces.
+    ///
+    /// Returns `0` if the description does not start with a text segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::ast::Description;
+    ///
+    /// let description = Description::from_text(" \tIndented");
+    /// assert_eq!(description.leading_spaces(), 5);
+    /// assert_eq!(description.leading_spaces_with_tab_width(2), 3);
+    /// ```
+    #[must_use]
+    pub fn leading_spaces_with_tab_width(&self, tab_width: usize) -> usize {
+        match self.body_items.first() {
+            Some(BodyItem::TextSegment(s)) => s
+                .chars()
+                .map_while(|c| match c {
+                    ' ' => Some(1),
+                    '\t' => Some(tab_width),
+                    _ => None,
+                })
+                .sum(),
+            _ => 0,
+        }
+    }
+
+    /// Returns an iterator over the text segments of the description.
+    pub fn text_segments(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.body_items.iter().filter_map(|item| match item {
+            BodyItem::TextSegment(s) => Some(*s),
+            BodyItem::InlineTag(_)
+            | BodyItem::CodeSpan(_)
+            | BodyItem::CodeBlock { .. }
+            | BodyItem::Link { .. }
+            | BodyItem::InheritDoc => None,
+        })
+    }
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockTag<'a> {
     pub name: &'a str,
+    /// 0-based index of the tag within the doc comment's `block_tags`.
+    pub position: usize,
     pub body_items: Vec<BodyItem<'a>>,
 }
 
+impl<'a> BlockTag<'a> {
+    /// Returns `true` if the name of the tag is valid in the standard tag name syntax,
+    /// i.e. a letter followed by letters, digits or underscores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::ast::BlockTag;
+    ///
+    /// let tag = BlockTag { name: "param", ..BlockTag::default() };
+    /// assert!(tag.validate_name());
+    /// let tag = BlockTag { name: "1param", ..BlockTag::default() };
+    /// assert!(!tag.validate_name());
+    /// ```
+    #[must_use]
+    pub fn validate_name(&self) -> bool {
+        is_valid_tag_name(self.name)
+    }
+
+    /// Returns `true` if the body of the tag contains an inline tag named `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** @param foo the {@link Foo} */").unwrap();
+    /// assert!(doc.block_tags[0].has_inline_tag("link"));
+    /// assert!(!doc.block_tags[0].has_inline_tag("code"));
+    /// ```
+    #[must_use]
+    pub fn has_inline_tag(&self, name: &str) -> bool {
+        self.body_items
+            .iter()
+            .any(|item| matches!(item, BodyItem::InlineTag(tag) if tag.name == name))
+    }
+
+    /// Returns an iterator over the inline tags in the body, in document order.
+    pub fn inline_tags(&self) -> impl Iterator<Item = &InlineTag<'a>> {
+        self.body_items.iter().filter_map(|item| match item {
+            BodyItem::InlineTag(tag) => Some(tag),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the names of the inline tags in the body, in document order.
+    pub fn inline_tag_names_in_body(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.inline_tags().map(|tag| tag.name)
+    }
+
+    /// Returns `true` if the body of the tag contains an inline tag named `name`.
+    ///
+    /// Same as [`BlockTag::has_inline_tag`], named after
+    /// [`DocComment::has_inline_tag_in_description`].
+    #[must_use]
+    pub fn has_inline_tag_in_body(&self, name: &str) -> bool {
+        self.has_inline_tag(name)
+    }
+
+    /// Returns `true` if the body of the tag has no items or only whitespace text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** @returns\n * @param foo */").unwrap();
+    /// assert!(doc.block_tags[0].is_empty());
+    /// assert!(!doc.block_tags[1].is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.body_items.iter().all(|item| match item {
+            BodyItem::TextSegment(s) => is_empty_or_multispace(s),
+            BodyItem::InlineTag(_)
+            | BodyItem::CodeSpan(_)
+            | BodyItem::CodeBlock { .. }
+            | BodyItem::Link { .. }
+            | BodyItem::InheritDoc => false,
+        })
+    }
+
+    /// Returns the body of the tag as a [`Description`], so that it can be processed with the
+    /// same APIs as the description of a doc comment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse;
+    ///
+    /// let doc = parse("/** @returns the {@link Foo} */").unwrap();
+    /// let description = doc.block_tags[0].body_as_description();
+    /// assert_eq!(description.inline_tags().count(), 1);
+    /// ```
+    #[must_use]
+    pub fn body_as_description(&self) -> Description<'a> {
+        Description {
+            body_items: self.body_items.clone(),
+        }
+    }
+
+    /// Returns the body items of the tag.
+    #[must_use]
+    pub fn body_items_ref(&self) -> &[BodyItem<'a>] {
+        &self.body_items
+    }
+
+    /// Consumes the tag, returning its body items.
+    #[must_use]
+    pub fn body_items_owned(self) -> Vec<BodyItem<'a>> {
+        self.body_items
+    }
+
+    /// Returns a copy of the tag with its body replaced by `body`.
+    #[must_use]
+    pub const fn clone_with_body(&self, body: Vec<BodyItem<'a>>) -> Self {
+        Self {
+            body_items: body,
+            ..*self
+        }
+    }
+
+    /// Returns a copy of the tag with its name replaced by `name`.
+    #[must_use]
+    pub fn clone_with_name(&self, name: &'a str) -> Self {
+        Self {
+            name,
+            ..self.clone()
+        }
+    }
+}
+
+#[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BodyItem<'a> {
     TextSegment(&'a str),
     InlineTag(InlineTag<'a>),
+    /// Content of a `{@code …}` inline tag, emitted if
+    /// [`ParseConfig::parse_code_spans`](crate::config::ParseConfig::parse_code_spans) is enabled.
+    CodeSpan(&'a str),
+    /// A fenced code block, emitted if
+    /// [`ParseConfig::parse_code_blocks`](crate::config::ParseConfig::parse_code_blocks) is enabled.
+    CodeBlock {
+        /// Language specified after the opening fence, e.g. `rust` in ` ```rust `.
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        language: Option<&'a str>,
+        /// Lines of code between the fences, without line leadings.
+        lines: Vec<&'a str>,
+    },
+    /// A `{@link target label}` inline tag, emitted if
+    /// [`ParseConfig::parse_links`](crate::config::ParseConfig::parse_links) is enabled.
+    Link {
+        /// The linked symbol or URL.
+        target: &'a str,
+        /// Text to display instead of the target, if any.
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        label: Option<&'a str>,
+    },
+    /// A `{@inheritDoc}` inline tag, emitted if
+    /// [`ParseConfig::parse_inherit_doc`](crate::config::ParseConfig::parse_inherit_doc) is enabled.
+    InheritDoc,
+}
+
+impl<'a> BodyItem<'a> {
+    /// Returns the text of the item with `<`, `>` and `&` replaced with HTML character
+    /// references, e.g. `&lt;`.
+    ///
+    /// Items other than text segments are rendered as plain text first, like in
+    /// [`DocComment::to_plain_text`].
+    /// The text is borrowed if the item is a text segment without any of these characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use doctor::ast::BodyItem;
+    ///
+    /// assert_eq!(BodyItem::TextSegment("a < b && c").escape_for_html(), "a &lt; b &amp;&amp; c");
+    /// assert!(matches!(BodyItem::TextSegment("a or b").escape_for_html(), Cow::Borrowed(_)));
+    /// ```
+    #[must_use]
+    pub fn escape_for_html(&self) -> Cow<'a, str> {
+        self.escape_text(|ch| match ch {
+            '<' => Some("&lt;"),
+            '>' => Some("&gt;"),
+            '&' => Some("&amp;"),
+            _ => None,
+        })
+    }
+
+    /// Returns the text of the item with the Markdown special characters `*`, `_`, `[`, `]`
+    /// and `\` escaped with a backslash.
+    ///
+    /// Items other than text segments are rendered as plain text first, like in
+    /// [`DocComment::to_plain_text`].
+    /// The text is borrowed if the item is a text segment without any of these characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::ast::BodyItem;
+    ///
+    /// assert_eq!(BodyItem::TextSegment("a_b [c]").escape_for_markdown(), "a\\_b \\[c\\]");
+    /// ```
+    #[must_use]
+    pub fn escape_for_markdown(&self) -> Cow<'a, str> {
+        self.escape_text(|ch| match ch {
+            '*' => Some("\\*"),
+            '_' => Some("\\_"),
+            '[' => Some("\\["),
+            ']' => Some("\\]"),
+            '\\' => Some("\\\\"),
+            _ => None,
+        })
+    }
+
+    /// Replaces the characters of the text of the item for which `escape` returns a replacement.
+    fn escape_text(&self, escape: impl Fn(char) -> Option<&'static str>) -> Cow<'a, str> {
+        let text = match self {
+            Self::TextSegment(s) => Cow::Borrowed(*s),
+            item => Cow::Owned(item_to_plain_text(item)),
+        };
+        if !text.chars().any(|ch| escape(ch).is_some()) {
+            return text;
+        }
+
+        let mut escaped = String::with_capacity(text.len());
+        for ch in text.chars() {
+            match escape(ch) {
+                Some(replacement) => escaped.push_str(replacement),
+                None => escaped.push(ch),
+            }
+        }
+        Cow::Owned(escaped)
+    }
+
+    /// Returns the number of characters of the text of the item: the text segment,
+    /// the body of an inline tag, the code of a code span or block and the label of a link,
+    /// or its target if it has no label. Line leadings aren't part of the text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::ast::{BodyItem, InlineTag};
+    ///
+    /// assert_eq!(BodyItem::TextSegment("héllo").char_count(), 5);
+    /// assert_eq!(BodyItem::InlineTag(InlineTag::new("link", "Foo")).char_count(), 3);
+    /// ```
+    #[must_use]
+    pub fn char_count(&self) -> usize {
+        match self {
+            Self::TextSegment(s) | Self::CodeSpan(s) => s.chars().count(),
+            Self::InlineTag(InlineTag {
+                body_lines: lines, ..
+            })
+            | Self::CodeBlock { lines, .. } => lines.iter().map(|line| line.chars().count()).sum(),
+            Self::Link { target, label } => label.unwrap_or(target).chars().count(),
+            Self::InheritDoc => 0,
+        }
+    }
+
+    /// Returns `true` if the item is a text segment or an inline tag, the variants emitted
+    /// with any [`ParseConfig`](crate::config::ParseConfig).
+    ///
+    /// `BodyItem` is `#[non_exhaustive]`: the other variants are only emitted when enabled
+    /// in the config, and new ones may be added the same way.
+    /// Code that only handles the two known variants can use this predicate to skip the rest,
+    /// e.g. in the wildcard arm of a `match`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::ast::{BodyItem, InlineTag};
+    ///
+    /// assert!(BodyItem::TextSegment("text").is_known());
+    /// assert!(BodyItem::InlineTag(InlineTag::new("link", "Foo")).is_known());
+    /// assert!(!BodyItem::CodeSpan("code").is_known());
+    /// ```
+    #[must_use]
+    pub const fn is_known(&self) -> bool {
+        matches!(self, Self::TextSegment(_) | Self::InlineTag(_))
+    }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
@@ -34,6 +1575,514 @@ pub struct InlineTag<'a> {
     pub body_lines: Vec<&'a str>,
 }
 
+impl<'a> InlineTag<'a> {
+    /// Returns `true` if the name of the tag is valid in the standard tag name syntax,
+    /// i.e. a letter followed by letters, digits or underscores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::ast::InlineTag;
+    ///
+    /// assert!(InlineTag::new("link", "Foo").validate_name());
+    /// assert!(!InlineTag::new("my-link", "Foo").validate_name());
+    /// ```
+    #[must_use]
+    pub fn validate_name(&self) -> bool {
+        is_valid_tag_name(self.name)
+    }
+
+    /// Creates an inline tag named `name` with `body` split into lines by [`InlineTag::split_body`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::ast::InlineTag;
+    ///
+    /// assert_eq!(
+    ///     InlineTag::new("link", "Foo"),
+    ///     InlineTag { name: "link", body_lines: vec!["Foo"] }
+    /// );
+    /// ```
+    #[must_use]
+    pub fn new(name: &'a str, body: &'a str) -> Self {
+        Self {
+            name,
+            body_lines: Self::split_body(body),
+        }
+    }
+
+    /// Splits `body` into lines the way the parser fills `body_lines`,
+    /// i.e. keeping the trailing `\n` of every line but the last one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::ast::InlineTag;
+    ///
+    /// assert_eq!(InlineTag::split_body("multi\nline"), vec!["multi\n", "line"]);
+    /// assert_eq!(InlineTag::split_body(""), Vec::<&str>::new());
+    /// ```
+    #[must_use]
+    pub fn split_body(body: &'a str) -> Vec<&'a str> {
+        body.split_inclusive('\n').collect()
+    }
+
+    /// Compares two inline tags ignoring differences in the whitespace of their bodies.
+    #[must_use]
+    pub fn semantically_eq(&self, other: &InlineTag<'_>) -> bool {
+        self.name == other.name
+            && normalized_words(&self.body_lines).eq(normalized_words(&other.body_lines))
+    }
+}
+
+impl Display for InlineTag<'_> {
+    /// Writes the tag as `{@name body}`, separating the lines of the body with ` * ` line leadings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::ast::InlineTag;
+    ///
+    /// assert_eq!(InlineTag::new("link", "Foo").to_string(), "{@link Foo}");
+    /// assert_eq!(
+    ///     InlineTag::new("code", "first\nsecond").to_string(),
+    ///     "{@code first\n * second}"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{{@{}", self.name)?;
+        for (index, line) in self.body_lines.iter().enumerate() {
+            let line = line.trim_end_matches(&['\r', '\n'][..]);
+            if index > 0 {
+                write!(f, "\n *")?;
+            }
+            if !line.is_empty() {
+                write!(f, " {line}")?;
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Returns `true` if `name` is accepted by the standard tag name parser.
+fn is_valid_tag_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|ch| ch.is_ascii_alphabetic())
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+/// Compares two bodies ignoring whitespace-only text segments and differences
+/// in the whitespace of the remaining items.
+fn body_eq_ignoring_whitespace(a: &[BodyItem<'_>], b: &[BodyItem<'_>]) -> bool {
+    fn non_blank<'b, 'a>(items: &'b [BodyItem<'a>]) -> impl Iterator<Item = &'b BodyItem<'a>> {
+        items
+            .iter()
+            .filter(|item| !matches!(item, BodyItem::TextSegment(s) if s.trim().is_empty()))
+    }
+
+    let mut a = non_blank(a);
+    let mut b = non_blank(b);
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return true,
+            (Some(BodyItem::TextSegment(a)), Some(BodyItem::TextSegment(b)))
+            | (Some(BodyItem::CodeSpan(a)), Some(BodyItem::CodeSpan(b))) => {
+                if !a.split_whitespace().eq(b.split_whitespace()) {
+                    return false;
+                }
+            }
+            (
+                Some(BodyItem::Link {
+                    target: a_target,
+                    label: a_label,
+                }),
+                Some(BodyItem::Link {
+                    target: b_target,
+                    label: b_label,
+                }),
+            ) => {
+                let a_label = a_label.unwrap_or("").split_whitespace();
+                let b_label = b_label.unwrap_or("").split_whitespace();
+                if a_target != b_target || !a_label.eq(b_label) {
+                    return false;
+                }
+            }
+            (Some(BodyItem::InlineTag(a)), Some(BodyItem::InlineTag(b))) => {
+                if !a.semantically_eq(b) {
+                    return false;
+                }
+            }
+            (Some(BodyItem::InheritDoc), Some(BodyItem::InheritDoc)) => {}
+            (Some(a @ BodyItem::CodeBlock { .. }), Some(b @ BodyItem::CodeBlock { .. })) => {
+                // Whitespace is significant in code.
+                if a != b {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+    }
+}
+
+/// Returns an iterator over the whitespace-separated words of `lines`.
+fn normalized_words<'a>(lines: &'a [&'a str]) -> impl Iterator<Item = &'a str> {
+    lines.iter().flat_map(|line| line.split_whitespace())
+}
+
+/// An owned counterpart of [`DocComment`] that doesn't borrow from the parsed input.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedDocComment {
+    pub description: Option<OwnedDescription>,
+    pub block_tags: Vec<OwnedBlockTag>,
+}
+
+impl OwnedDocComment {
+    /// Returns `true` if the comment has neither block tags nor a description with any items,
+    /// as is the case for [`OwnedDocComment::default`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use doctor::ast::OwnedDocComment;
+    ///
+    /// assert!(OwnedDocComment::default().is_empty());
+    /// assert!(!OwnedDocComment::try_from("/** Description */").unwrap().is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.block_tags.is_empty()
+            && self
+                .description
+                .iter()
+                .all(|description| description.body_items.is_empty())
+    }
+
+    /// Returns the block tag at `index`, or `None` if there are not enough tags.
+    #[must_use]
+    pub fn tag_at(&self, index: usize) -> Option<&OwnedBlockTag> {
+        self.block_tags.get(index)
+    }
+
+    /// Returns a mutable reference to the block tag at `index`,
+    /// or `None` if there are not enough tags.
+    pub fn tag_at_mut(&mut self, index: usize) -> Option<&mut OwnedBlockTag> {
+        self.block_tags.get_mut(index)
+    }
+
+    /// Returns a mutable reference to the description, inserting an empty one if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::ast::{OwnedBodyItem, OwnedDocComment};
+    ///
+    /// let mut doc = OwnedDocComment::default();
+    /// doc
+    ///     .ensure_description()
+    ///     .body_items
+    ///     .push(OwnedBodyItem::TextSegment("Description.".to_owned()));
+    ///
+    /// assert_eq!(doc.description.unwrap().body_items.len(), 1);
+    /// ```
+    pub fn ensure_description(&mut self) -> &mut OwnedDescription {
+        self.description
+            .get_or_insert_with(OwnedDescription::default)
+    }
+
+    /// Removes the block tags equal to an earlier tag, ignoring differences in whitespace,
+    /// and updates the `position` of the remaining ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use doctor::ast::OwnedDocComment;
+    ///
+    /// let mut doc = OwnedDocComment::try_from("/** @param foo the  foo\n * @param foo the foo */").unwrap();
+    /// doc.dedup_tags();
+    /// assert_eq!(doc.block_tags.len(), 1);
+    /// ```
+    pub fn dedup_tags(&mut self) {
+        let mut seen = HashSet::new();
+        self.retain_tags(|tag| {
+            seen.insert((tag.name.clone(), normalize_whitespace(&tag.body_items)))
+        });
+    }
+
+    /// Removes the block tags with the same name as an earlier tag,
+    /// and updates the `position` of the remaining ones.
+    pub fn dedup_tags_by_name(&mut self) {
+        let mut seen = HashSet::new();
+        self.retain_tags(|tag| seen.insert(tag.name.clone()));
+    }
+
+    fn retain_tags(&mut self, f: impl FnMut(&OwnedBlockTag) -> bool) {
+        self.block_tags.retain(f);
+        for (position, tag) in self.block_tags.iter_mut().enumerate() {
+            tag.position = position;
+        }
+    }
+}
+
+/// Returns `body_items` with adjacent text segments merged, the whitespace in them collapsed
+/// and blank ones removed.
+/// The whitespace in code spans, link labels and inline tag bodies is collapsed as well,
+/// the body lines of an inline tag being joined into a single one.
+fn normalize_whitespace(body_items: &[OwnedBodyItem]) -> Vec<OwnedBodyItem> {
+    let collapse = |s: &str| s.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut items: Vec<OwnedBodyItem> = Vec::new();
+    for item in body_items {
+        match (items.last_mut(), item) {
+            (Some(OwnedBodyItem::TextSegment(text)), OwnedBodyItem::TextSegment(s)) => {
+                text.push(' ');
+                text.push_str(s);
+            }
+            (_, OwnedBodyItem::CodeSpan(code)) => {
+                items.push(OwnedBodyItem::CodeSpan(collapse(code)));
+            }
+            (_, OwnedBodyItem::InlineTag(tag)) => {
+                let body = collapse(&tag.body_lines.join(" "));
+                items.push(OwnedBodyItem::InlineTag(OwnedInlineTag {
+                    name: tag.name.clone(),
+                    body_lines: Some(body)
+                        .filter(|body| !body.is_empty())
+                        .into_iter()
+                        .collect(),
+                }));
+            }
+            (_, OwnedBodyItem::Link { target, label }) => {
+                items.push(OwnedBodyItem::Link {
+                    target: target.clone(),
+                    label: label.as_deref().map(collapse),
+                });
+            }
+            (_, item) => items.push(item.clone()),
+        }
+    }
+    items
+        .into_iter()
+        .filter_map(|item| match item {
+            OwnedBodyItem::TextSegment(s) => {
+                let s = collapse(&s);
+                (!s.is_empty()).then_some(OwnedBodyItem::TextSegment(s))
+            }
+            item => Some(item),
+        })
+        .collect()
+}
+
+/// An owned counterpart of [`Description`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedDescription {
+    pub body_items: Vec<OwnedBodyItem>,
+}
+
+/// An owned counterpart of [`BlockTag`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedBlockTag {
+    pub name: String,
+    /// 0-based index of the tag within the doc comment's `block_tags`.
+    pub position: usize,
+    pub body_items: Vec<OwnedBodyItem>,
+}
+
+/// An owned counterpart of [`BodyItem`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OwnedBodyItem {
+    TextSegment(String),
+    InlineTag(OwnedInlineTag),
+    CodeSpan(String),
+    CodeBlock {
+        language: Option<String>,
+        lines: Vec<String>,
+    },
+    Link {
+        target: String,
+        label: Option<String>,
+    },
+    InheritDoc,
+}
+
+/// An owned counterpart of [`InlineTag`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedInlineTag {
+    pub name: String,
+    pub body_lines: Vec<String>,
+}
+
+impl From<&DocComment<'_>> for OwnedDocComment {
+    fn from(doc: &DocComment<'_>) -> Self {
+        Self {
+            description: doc.description.as_ref().map(OwnedDescription::from),
+            block_tags: doc.block_tags.iter().map(OwnedBlockTag::from).collect(),
+        }
+    }
+}
+
+impl From<&Description<'_>> for OwnedDescription {
+    fn from(description: &Description<'_>) -> Self {
+        Self {
+            body_items: description
+                .body_items
+                .iter()
+                .map(OwnedBodyItem::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<&BlockTag<'_>> for OwnedBlockTag {
+    fn from(tag: &BlockTag<'_>) -> Self {
+        Self {
+            name: tag.name.to_owned(),
+            position: tag.position,
+            body_items: tag.body_items.iter().map(OwnedBodyItem::from).collect(),
+        }
+    }
+}
+
+impl From<&BodyItem<'_>> for OwnedBodyItem {
+    fn from(item: &BodyItem<'_>) -> Self {
+        match item {
+            BodyItem::TextSegment(s) => Self::TextSegment((*s).to_owned()),
+            BodyItem::InlineTag(tag) => Self::InlineTag(OwnedInlineTag::from(tag)),
+            BodyItem::CodeSpan(code) => Self::CodeSpan((*code).to_owned()),
+            BodyItem::CodeBlock { language, lines } => Self::CodeBlock {
+                language: language.map(str::to_owned),
+                lines: lines.iter().map(|line| (*line).to_owned()).collect(),
+            },
+            BodyItem::Link { target, label } => Self::Link {
+                target: (*target).to_owned(),
+                label: label.map(str::to_owned),
+            },
+            BodyItem::InheritDoc => Self::InheritDoc,
+        }
+    }
+}
+
+impl From<&InlineTag<'_>> for OwnedInlineTag {
+    fn from(tag: &InlineTag<'_>) -> Self {
+        Self {
+            name: tag.name.to_owned(),
+            body_lines: tag.body_lines.iter().map(|&line| line.to_owned()).collect(),
+        }
+    }
+}
+
+impl PartialEq<OwnedDocComment> for DocComment<'_> {
+    /// Compares the comments structurally, without converting either of them.
+    fn eq(&self, other: &OwnedDocComment) -> bool {
+        let descriptions_eq = match (&self.description, &other.description) {
+            (Some(description), Some(other)) => description == other,
+            (None, None) => true,
+            _ => false,
+        };
+        descriptions_eq && self.block_tags == other.block_tags
+    }
+}
+
+impl PartialEq<DocComment<'_>> for OwnedDocComment {
+    fn eq(&self, other: &DocComment<'_>) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<OwnedDescription> for Description<'_> {
+    fn eq(&self, other: &OwnedDescription) -> bool {
+        self.body_items == other.body_items
+    }
+}
+
+impl PartialEq<Description<'_>> for OwnedDescription {
+    fn eq(&self, other: &Description<'_>) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<OwnedBlockTag> for BlockTag<'_> {
+    fn eq(&self, other: &OwnedBlockTag) -> bool {
+        self.name == other.name
+            && self.position == other.position
+            && self.body_items == other.body_items
+    }
+}
+
+impl PartialEq<BlockTag<'_>> for OwnedBlockTag {
+    fn eq(&self, other: &BlockTag<'_>) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<OwnedBodyItem> for BodyItem<'_> {
+    fn eq(&self, other: &OwnedBodyItem) -> bool {
+        match (self, other) {
+            (Self::TextSegment(s), OwnedBodyItem::TextSegment(other))
+            | (Self::CodeSpan(s), OwnedBodyItem::CodeSpan(other)) => s == other,
+            (Self::InlineTag(tag), OwnedBodyItem::InlineTag(other)) => tag == other,
+            (
+                Self::CodeBlock { language, lines },
+                OwnedBodyItem::CodeBlock {
+                    language: other_language,
+                    lines: other_lines,
+                },
+            ) => *language == other_language.as_deref() && lines == other_lines,
+            (
+                Self::Link { target, label },
+                OwnedBodyItem::Link {
+                    target: other_target,
+                    label: other_label,
+                },
+            ) => target == other_target && *label == other_label.as_deref(),
+            (Self::InheritDoc, OwnedBodyItem::InheritDoc) => true,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<BodyItem<'_>> for OwnedBodyItem {
+    fn eq(&self, other: &BodyItem<'_>) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<OwnedInlineTag> for InlineTag<'_> {
+    fn eq(&self, other: &OwnedInlineTag) -> bool {
+        self.name == other.name && self.body_lines == other.body_lines
+    }
+}
+
+impl PartialEq<InlineTag<'_>> for OwnedInlineTag {
+    fn eq(&self, other: &InlineTag<'_>) -> bool {
+        other == self
+    }
+}
+
+impl TryFrom<&str> for OwnedDocComment {
+    type Error = Error;
+
+    /// Parses `input` with [`parse`](crate::parse) and converts the result into an owned doc comment.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        crate::parse(input).map(|doc| Self::from(&doc))
+    }
+}
+
+impl TryFrom<String> for OwnedDocComment {
+    type Error = Error;
+
+    /// Parses `input` with [`parse`](crate::parse) and converts the result into an owned doc comment.
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        Self::try_from(input.as_str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::fmt::Debug;
@@ -61,6 +2110,1052 @@ mod tests {
         assert_sync_send::<DocComment>();
 
         #[cfg(feature = "serde")]
-        assert_serde::<DocComment>()
+        assert_serde::<DocComment>();
+    }
+
+    #[test]
+    fn test_owned_doc_comment_implement_common_traits() {
+        assert_default::<OwnedDocComment>();
+        assert_clone::<OwnedDocComment>();
+        assert_debug::<OwnedDocComment>();
+        assert_hash::<OwnedDocComment>();
+        assert_sync_send::<OwnedDocComment>();
+
+        #[cfg(feature = "serde")]
+        assert_serde::<OwnedDocComment>();
+    }
+
+    #[test]
+    fn test_owned_doc_comment_from_doc_comment() {
+        let doc = crate::parse("/** Description {@link Foo}\n * @param foo bar */").unwrap();
+
+        assert_eq!(
+            OwnedDocComment::from(&doc),
+            OwnedDocComment {
+                description: Some(OwnedDescription {
+                    body_items: vec![
+                        OwnedBodyItem::TextSegment("Description ".to_owned()),
+                        OwnedBodyItem::InlineTag(OwnedInlineTag {
+                            name: "link".to_owned(),
+                            body_lines: vec!["Foo".to_owned()],
+                        }),
+                        OwnedBodyItem::TextSegment("\n".to_owned()),
+                    ]
+                }),
+                block_tags: vec![OwnedBlockTag {
+                    name: "param".to_owned(),
+                    position: 0,
+                    body_items: vec![OwnedBodyItem::TextSegment("foo bar ".to_owned())],
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_doc_comment_text_segments() {
+        let doc = crate::parse(
+            r"/**
+              * Description with {@inlineTag some body}.
+              * @param foo {@link Foo}
+              * @returns bar
+              */",
+        )
+        .unwrap();
+
+        assert_eq!(
+            doc.text_segments().collect::<Vec<_>>(),
+            vec![
+                "Description with ",
+                "some body",
+                ".\n",
+                "foo ",
+                "Foo",
+                "\n",
+                "bar\n",
+            ]
+        );
+        assert_eq!(DocComment::default().text_segments().next(), None);
+    }
+    #[test]
+    fn test_inline_tag_semantically_eq() {
+        let tag = InlineTag {
+            name: "link",
+            body_lines: vec!["Foo bar"],
+        };
+
+        assert!(tag.semantically_eq(&InlineTag {
+            name: "link",
+            body_lines: vec!["  Foo \t bar "],
+        }));
+        assert!(tag.semantically_eq(&InlineTag {
+            name: "link",
+            body_lines: vec!["Foo\n", "bar"],
+        }));
+        assert!(!tag.semantically_eq(&InlineTag {
+            name: "link",
+            body_lines: vec!["Foobar"],
+        }));
+        assert!(!tag.semantically_eq(&InlineTag {
+            name: "code",
+            body_lines: vec!["Foo bar"],
+        }));
+    }
+    #[test]
+    fn test_equal_doc_comments_have_equal_hashes() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::Hasher;
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let first_input = String::from("/** Description {@link Foo}\n * @param foo bar */");
+        let second_input = first_input.clone();
+        let first = crate::parse(&first_input).unwrap();
+        let second = crate::parse(&second_input).unwrap();
+
+        assert_ne!(first_input.as_ptr(), second_input.as_ptr());
+        assert_eq!(first, second);
+        assert_eq!(hash_of(&first), hash_of(&second));
+    }
+    #[test]
+    fn test_description_inline_tags_and_text_segments() {
+        let doc =
+            crate::parse("/** See {@link Foo} and {@link Bar baz}.\n * @param foo {@link Qux} */")
+                .unwrap();
+        let description = doc.description.unwrap();
+
+        assert_eq!(
+            description.inline_tags().collect::<Vec<_>>(),
+            vec![
+                &InlineTag {
+                    name: "link",
+                    body_lines: vec!["Foo"],
+                },
+                &InlineTag {
+                    name: "link",
+                    body_lines: vec!["Bar baz"],
+                },
+            ]
+        );
+        assert_eq!(
+            description.text_segments().collect::<Vec<_>>(),
+            vec!["See ", "and ", ".\n"]
+        );
+        assert_eq!(Description::default().inline_tags().next(), None);
+        assert_eq!(Description::default().text_segments().next(), None);
+    }
+    #[test]
+    fn test_block_tag_clone_with() {
+        let tag = BlockTag {
+            name: "param",
+            position: 1,
+            body_items: vec![
+                BodyItem::TextSegment("foo "),
+                BodyItem::InlineTag(InlineTag {
+                    name: "link",
+                    body_lines: vec!["Foo"],
+                }),
+            ],
+        };
+
+        assert_eq!(
+            tag.clone_with_body(
+                tag.body_items
+                    .iter()
+                    .filter(|item| matches!(item, BodyItem::TextSegment(_)))
+                    .cloned()
+                    .collect()
+            ),
+            BlockTag {
+                name: "param",
+                position: 1,
+                body_items: vec![BodyItem::TextSegment("foo ")],
+            }
+        );
+        assert_eq!(
+            tag.clone_with_name("arg"),
+            BlockTag {
+                name: "arg",
+                ..tag.clone()
+            }
+        );
+    }
+    #[test]
+    fn test_body_items_accessors() {
+        let doc = crate::parse("/** Description {@link Foo}\n * @param foo */").unwrap();
+        let description = doc.description.clone().unwrap();
+        let tag = doc.block_tags[0].clone();
+
+        assert_eq!(description.body_items_ref(), &description.body_items[..]);
+        assert_eq!(tag.body_items_ref(), &tag.body_items[..]);
+        assert_eq!(
+            description.clone().body_items_owned(),
+            description.body_items
+        );
+        assert_eq!(tag.clone().body_items_owned(), tag.body_items);
+    }
+    #[test]
+    fn test_body_item_is_known() {
+        assert!(BodyItem::TextSegment("text").is_known());
+        assert!(BodyItem::InlineTag(InlineTag::default()).is_known());
+        assert!(!BodyItem::CodeSpan("code").is_known());
+        assert!(!BodyItem::InheritDoc.is_known());
+        assert!(!BodyItem::CodeBlock {
+            language: None,
+            lines: vec![]
+        }
+        .is_known());
+        assert!(!BodyItem::Link {
+            target: "Foo",
+            label: None
+        }
+        .is_known());
+
+        let doc = crate::parse("/** See {@link Foo} and {@code bar}. */").unwrap();
+        assert!(doc
+            .description
+            .unwrap()
+            .body_items
+            .iter()
+            .all(BodyItem::is_known));
+    }
+    #[test]
+    fn test_group_params() {
+        let doc = crate::parse(
+            r"/**
+            * @param options.retries the number of retries
+            * @param callback the callback
+            * @param options
+            * @param options.timeout.ms the timeout
+            * @returns nothing
+            */",
+        )
+        .unwrap();
+
+        assert_eq!(
+            doc.group_params(),
+            vec![
+                ParamGroup {
+                    name: "options",
+                    description: None,
+                    properties: vec![&doc.block_tags[0], &doc.block_tags[3]],
+                },
+                ParamGroup {
+                    name: "callback",
+                    description: Some("the callback".to_owned()),
+                    properties: vec![],
+                },
+            ]
+        );
+        assert_eq!(DocComment::default().group_params(), vec![]);
+    }
+    #[test]
+    fn test_prop_tags() {
+        let config = crate::config::ParseConfig {
+            parse_prop_tags: true,
+            ..crate::config::ParseConfig::default()
+        };
+        let doc = crate::parse_with_config(
+            r"/**
+            * @prop {Object} style - the {@code style} object
+            * @prop onClick click handler
+            * @prop { number } count
+            * @param foo
+            */",
+            config,
+        )
+        .unwrap();
+
+        assert_eq!(
+            doc.prop_tags(),
+            vec![
+                PropTag {
+                    prop_type: Some("Object"),
+                    name: "style",
+                    description: Some("the style object".to_owned()),
+                },
+                PropTag {
+                    prop_type: None,
+                    name: "onClick",
+                    description: Some("click handler".to_owned()),
+                },
+                PropTag {
+                    prop_type: Some("number"),
+                    name: "count",
+                    description: None,
+                },
+            ]
+        );
+    }
+    #[test]
+    fn test_tags_of_type() {
+        let doc =
+            crate::parse("/** @param a\n * @throws E\n * @param b\n * @since 1.0 */").unwrap();
+        let tags = doc.tags_of_type(&["param", "returns", "throws"]);
+
+        assert_eq!(tags.len(), 3);
+        assert_eq!(tags["param"], vec![&doc.block_tags[0], &doc.block_tags[2]]);
+        assert_eq!(tags["returns"], Vec::<&BlockTag>::new());
+        assert_eq!(tags["throws"], vec![&doc.block_tags[1]]);
+        assert!(doc.tags_of_type(&[]).is_empty());
+    }
+    #[test]
+    fn test_description_sentence_count() {
+        let sentence_count = |input| {
+            crate::parse(input)
+                .unwrap()
+                .description
+                .map_or(0, |description| description.sentence_count())
+        };
+
+        assert_eq!(sentence_count("/** No terminal punctuation */"), 0);
+        assert_eq!(sentence_count("/** One sentence. */"), 1);
+        assert_eq!(
+            sentence_count("/**\n * First one. Second one!\n * Third\n * one? */"),
+            3
+        );
+        assert_eq!(
+            sentence_count("/** See {@link Foo}. Use {@code bar.} too */"),
+            2
+        );
+        assert_eq!(
+            sentence_count("/** Split across {@link Foo the. inline} tag. */"),
+            2
+        );
+    }
+    #[test]
+    fn test_leading_spaces() {
+        assert_eq!(Description::from_text("No indentation").leading_spaces(), 0);
+        assert_eq!(Description::from_text("  \t  text").leading_spaces(), 8);
+        assert_eq!(
+            Description::from_text("\t\ttext").leading_spaces_with_tab_width(8),
+            16
+        );
+        assert_eq!(Description::from_text("   ").leading_spaces(), 3);
+        assert_eq!(
+            Description::default()
+                .append_inline_tag(InlineTag::new("link", "Foo"))
+                .append_text("  text")
+                .leading_spaces(),
+            0
+        );
+    }
+    #[test]
+    fn test_validate_name() {
+        for name in &["a", "param", "myTag_2", "inheritDoc"] {
+            assert!(InlineTag::new(name, "").validate_name(), "{}", name);
+            assert!(BlockTag {
+                name,
+                ..BlockTag::default()
+            }
+            .validate_name());
+        }
+        for name in &["", "1tag", "_tag", "my-tag", "tag!", "ta g", "täg"] {
+            assert!(!InlineTag::new(name, "").validate_name(), "{}", name);
+            assert!(!BlockTag {
+                name,
+                ..BlockTag::default()
+            }
+            .validate_name());
+        }
+    }
+    #[test]
+    fn test_inline_tag_display() {
+        assert_eq!(
+            InlineTag::new("inheritDoc", "").to_string(),
+            "{@inheritDoc}"
+        );
+        assert_eq!(
+            InlineTag::new("link", "Foo the foo").to_string(),
+            "{@link Foo the foo}"
+        );
+        assert_eq!(
+            InlineTag::new("code", "\nfirst\r\n\nthird").to_string(),
+            "{@code\n * first\n *\n * third}"
+        );
+
+        let doc = crate::parse("/** {@link Foo\n * bar} */").unwrap();
+        let tag = doc
+            .description
+            .unwrap()
+            .inline_tags()
+            .next()
+            .cloned()
+            .unwrap();
+        assert_eq!(tag.to_string(), "{@link Foo\n * bar}");
+    }
+    #[test]
+    fn test_inline_tag_new() {
+        assert_eq!(
+            InlineTag::new("code", ""),
+            InlineTag {
+                name: "code",
+                body_lines: vec![]
+            }
+        );
+        assert_eq!(
+            InlineTag::split_body("first\nsecond\n"),
+            vec!["first\n", "second\n"]
+        );
+
+        let doc = crate::parse("/** {@link multi\n * line body} */").unwrap();
+        assert_eq!(
+            doc.description.unwrap().body_items,
+            vec![BodyItem::InlineTag(InlineTag::new(
+                "link",
+                "multi\nline body"
+            ))]
+        );
+    }
+    #[test]
+    fn test_description_or_empty() {
+        let doc = crate::parse("/** Description. */").unwrap();
+        assert_eq!(
+            doc.description_or_empty(),
+            doc.description.as_ref().unwrap()
+        );
+        assert_eq!(
+            DocComment::default().description_or_empty(),
+            &Description::default()
+        );
+    }
+    #[test]
+    fn test_block_tag_has_inline_tag() {
+        let doc = crate::parse(
+            r"/**
+            * @param foo the {@link Foo} or {@code null}, see {@linkplain Bar}
+            * @param bar the bar
+            */",
+        )
+        .unwrap();
+
+        assert!(doc.block_tags[0].has_inline_tag("link"));
+        assert!(doc.block_tags[0].has_inline_tag("code"));
+        assert!(doc.block_tags[0].has_inline_tag("linkplain"));
+        assert!(!doc.block_tags[0].has_inline_tag("see"));
+        assert!(!doc.block_tags[1].has_inline_tag("link"));
+        assert_eq!(
+            doc.block_tags
+                .iter()
+                .filter(|tag| tag.name == "param" && tag.has_inline_tag("link"))
+                .count(),
+            1
+        );
+    }
+    #[test]
+    fn test_equals_ignoring_whitespace() {
+        let doc = crate::parse(
+            "/**\n * Description with {@link Foo  the foo}.\n *\n * @param foo the foo\n */",
+        )
+        .unwrap();
+
+        for equal in [
+            "/**\n * Description  with {@link Foo the foo}. \n * @param foo   the foo */",
+            "/**\n\t\t* Description with {@link Foo the\n\t\t* foo}.\n\t\t*\n\t\t* @param foo the foo\n\t\t*/",
+        ] {
+            assert!(doc.equals_ignoring_whitespace(&crate::parse(equal).unwrap()), "{}", equal);
+        }
+        for different in [
+            "/** Description with {@link Foo the foo}. */",
+            "/** Description with {@link Bar the foo}.\n * @param foo the foo */",
+            "/** Description with {@link Foo the foo}!\n * @param foo the foo */",
+            "/** Description with {@link Foo the foo}.\n * @arg foo the foo */",
+        ] {
+            assert!(
+                !doc.equals_ignoring_whitespace(&crate::parse(different).unwrap()),
+                "{}",
+                different
+            );
+        }
+    }
+    #[test]
+    fn test_description_builders() {
+        assert_eq!(
+            Description::from_text("Description with ")
+                .append_inline_tag(InlineTag::new("link", "Foo"))
+                .append_text(".\n"),
+            crate::parse("/** Description with {@link Foo}.\n */")
+                .unwrap()
+                .description
+                .unwrap()
+        );
+        assert_eq!(
+            Description::default().append_text("text"),
+            Description::from_text("text")
+        );
+    }
+    #[test]
+    fn test_doc_comment_consuming_accessors() {
+        let doc = crate::parse("/** Description.\n * @param foo */").unwrap();
+
+        assert_eq!(doc.clone().into_block_tags(), doc.block_tags);
+        assert_eq!(doc.clone().into_description(), doc.description);
+        assert_eq!(DocComment::default().into_description(), None);
+    }
+    #[test]
+    fn test_owned_doc_comment_try_from() {
+        fn parse_owned(input: &str) -> Result<OwnedDocComment, Error> {
+            let doc = OwnedDocComment::try_from(input)?;
+            Ok(doc)
+        }
+
+        let expected = OwnedDocComment::from(&crate::parse("/** hello */").unwrap());
+        assert_eq!(parse_owned("/** hello */"), Ok(expected.clone()));
+        assert_eq!(
+            OwnedDocComment::try_from(String::from("/** hello */")),
+            Ok(expected)
+        );
+
+        let err: Error = OwnedDocComment::try_from("not a comment").unwrap_err();
+        assert_eq!(Err(err), crate::parse("not a comment"));
+        assert!(OwnedDocComment::try_from(String::from("/** unterminated")).is_err());
+    }
+    #[test]
+    fn test_count_description_words() {
+        let count = |input| crate::parse(input).unwrap().count_description_words();
+
+        assert_eq!(count("/** @param foo the foo */"), 0);
+        assert_eq!(count("/**\n * Two  words\n *\n * and three more.\n */"), 5);
+        assert_eq!(count("/** {@link Foo the foo} */"), 3);
+        assert_eq!(count("/** Call {@code foo()} now. */"), 3);
+    }
+    #[test]
+    fn test_tag_at() {
+        let mut doc = crate::parse("/** @param foo\n * @param bar */").unwrap();
+
+        assert_eq!(doc.tag_at(1), Some(&doc.block_tags[1]));
+        assert_eq!(doc.tag_at(2), None);
+        doc.tag_at_mut(0).unwrap().name = "arg";
+        assert_eq!(doc.block_tags[0].name, "arg");
+
+        let mut owned = OwnedDocComment::from(&doc);
+        assert_eq!(owned.tag_at(0).map(|tag| tag.name.as_str()), Some("arg"));
+        owned.tag_at_mut(1).unwrap().name = String::from("argument");
+        assert_eq!(owned.block_tags[1].name, "argument");
+        assert!(owned.tag_at_mut(2).is_none());
+    }
+    #[test]
+    fn test_extend_tags() {
+        let doc = crate::parse("/** Description.\n * @param foo */").unwrap();
+        let other = crate::parse("/** @param bar\n * @returns baz */").unwrap();
+
+        let extended = doc.clone().extend_tags(other.block_tags.clone());
+        assert_eq!(extended.description, doc.description);
+        assert_eq!(
+            extended
+                .block_tags
+                .iter()
+                .map(|tag| (tag.position, tag.name))
+                .collect::<Vec<_>>(),
+            vec![(0, "param"), (1, "param"), (2, "returns")]
+        );
+
+        let mut doc = doc;
+        doc.extend(other.block_tags);
+        assert_eq!(extended, doc);
+    }
+    #[test]
+    fn test_prepend_description_text() {
+        let doc = crate::parse("/** @param foo */").unwrap();
+        let prepended = doc.prepend_description_text("Note.");
+        assert_eq!(prepended.description, Some(Description::from_text("Note.")));
+        assert_eq!(prepended.block_tags, doc.block_tags);
+
+        let doc = crate::parse("/** See {@link Foo}. */").unwrap();
+        let prepended = doc.prepend_description_text("Note. ");
+        assert_eq!(
+            prepended.description.unwrap().body_items,
+            [
+                vec![BodyItem::TextSegment("Note. ")],
+                doc.description.unwrap().body_items
+            ]
+            .concat()
+        );
+    }
+    #[test]
+    fn test_take_tags_by_name() {
+        let doc =
+            crate::parse("/** @param foo\n * @throws Error\n * @param bar\n * @returns baz */")
+                .unwrap();
+
+        let (params, rest) = doc.take_tags_by_name("param");
+        assert_eq!(params, vec![&doc.block_tags[0], &doc.block_tags[2]]);
+        assert_eq!(rest.description, None);
+        let tags: Vec<_> = rest
+            .block_tags
+            .iter()
+            .map(|tag| (tag.position, tag.name))
+            .collect();
+        assert_eq!(tags, vec![(0, "throws"), (1, "returns")]);
+
+        let (taken, rest) = doc.take_tags_by_name("since");
+        assert!(taken.is_empty());
+        assert_eq!(rest, doc);
+    }
+    #[test]
+    fn test_map_text() {
+        let doc = crate::parse_with_config(
+            "/** See {@link Foo the foo}, {@code foo()}.\n * @see {@linkplain Bar} */",
+            crate::config::ParseConfig {
+                parse_code_spans: true,
+                ..crate::config::ParseConfig::default()
+            },
+        )
+        .unwrap();
+        let mapped = doc.map_text(|s| format!("<{s}>"));
+
+        assert_eq!(
+            mapped.description.unwrap().body_items,
+            vec![
+                OwnedBodyItem::TextSegment("<See >".to_owned()),
+                OwnedBodyItem::InlineTag(OwnedInlineTag {
+                    name: "link".to_owned(),
+                    body_lines: vec!["<Foo the foo>".to_owned()],
+                }),
+                OwnedBodyItem::TextSegment("<, >".to_owned()),
+                OwnedBodyItem::CodeSpan("foo()".to_owned()),
+                OwnedBodyItem::TextSegment("<.\n>".to_owned()),
+            ]
+        );
+        assert_eq!(mapped.block_tags[0].name, "see");
+        assert_eq!(mapped.block_tags[0].position, 0);
+        assert_eq!(
+            mapped.block_tags[0].body_items,
+            vec![OwnedBodyItem::InlineTag(OwnedInlineTag {
+                name: "linkplain".to_owned(),
+                body_lines: vec!["<Bar>".to_owned()],
+            })]
+        );
+    }
+    #[test]
+    fn test_longest_block_tag_name() {
+        let longest = |input| crate::parse(input).unwrap().longest_block_tag_name();
+
+        assert_eq!(longest("/** Description. */"), None);
+        assert_eq!(longest("/** @param foo */"), Some("param"));
+        assert_eq!(
+            longest("/** @since 1\n * @param foo\n * @see bar */"),
+            Some("since")
+        );
+    }
+    #[test]
+    fn test_see_tags() {
+        let doc = crate::parse_with_config(
+            r"/**
+            * Description.
+            * @see http://example.com
+            * @see {@link Foo#bar the bar}
+            * @see the {@link Baz}
+            * @see {@link Qux|label}
+            * @see  Some reference
+            * @see
+            * @param foo
+            */",
+            crate::config::ParseConfig {
+                parse_links: true,
+                ..crate::config::ParseConfig::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            doc.see_tags(),
+            vec![
+                SeeRef::Url("http://example.com"),
+                SeeRef::Link("Foo#bar"),
+                SeeRef::Link("Baz"),
+                SeeRef::Link("Qux"),
+                SeeRef::Text("Some reference"),
+                SeeRef::Text(""),
+            ]
+        );
+        assert_eq!(
+            crate::parse("/** @see {@link Foo the foo} */")
+                .unwrap()
+                .see_tags(),
+            vec![SeeRef::Link("Foo")]
+        );
+    }
+    #[test]
+    fn test_has_inline_tag_in_description() {
+        let doc = crate::parse("/** See {@link Foo}.\n * @param foo the {@code foo} */").unwrap();
+
+        assert!(doc.has_inline_tag_in_description("link"));
+        assert!(!doc.has_inline_tag_in_description("code"));
+        assert!(doc.block_tags[0].has_inline_tag_in_body("code"));
+        assert!(!doc.block_tags[0].has_inline_tag_in_body("link"));
+        assert!(!crate::parse("/** @param foo */")
+            .unwrap()
+            .has_inline_tag_in_description("link"));
+    }
+    #[test]
+    fn test_tag_names() {
+        let doc = crate::parse(
+            "/** See {@link Foo} and {@code bar}.\n * @param foo {@code foo}\n * @returns */",
+        )
+        .unwrap();
+
+        assert_eq!(
+            doc.tag_names().collect::<Vec<_>>(),
+            vec!["param", "returns"]
+        );
+        assert_eq!(
+            doc.inline_tag_names_in_description().collect::<Vec<_>>(),
+            vec!["link", "code"]
+        );
+        assert_eq!(
+            doc.block_tags[0]
+                .inline_tag_names_in_body()
+                .collect::<Vec<_>>(),
+            vec!["code"]
+        );
+        assert_eq!(doc.block_tags[1].inline_tag_names_in_body().count(), 0);
+        assert_eq!(
+            crate::parse("/** @param foo */")
+                .unwrap()
+                .inline_tag_names_in_description()
+                .count(),
+            0
+        );
+    }
+    #[test]
+    fn test_ensure_description() {
+        let mut doc = OwnedDocComment::default();
+        assert!(doc.ensure_description().body_items.is_empty());
+        assert_eq!(doc.description, Some(OwnedDescription::default()));
+
+        let mut doc = OwnedDocComment::try_from("/** Description. */").unwrap();
+        let expected = doc.description.clone();
+        doc.ensure_description();
+        assert_eq!(doc.description, expected);
+    }
+    #[test]
+    fn test_all_inline_tags() {
+        let doc = crate::parse(
+            r"/**
+            * {@link A} and {@code b}.
+            * @param foo {@link C}
+            * @returns nothing
+            * @throws {@link D} if {@code e}
+            */",
+        )
+        .unwrap();
+        let names: Vec<_> = doc.all_inline_tags().map(|tag| tag.body_lines[0]).collect();
+        assert_eq!(names, vec!["A", "b", "C", "D", "e"]);
+
+        assert_eq!(
+            crate::parse("/** @param foo */")
+                .unwrap()
+                .all_inline_tags()
+                .count(),
+            0
+        );
+    }
+    #[test]
+    fn test_dedup_tags() {
+        let input = r"/**
+            * @param foo the foo
+            * @param bar
+            * @param foo the
+            *   foo
+            * @param foo another foo
+            * @param bar
+            */";
+
+        let mut doc = OwnedDocComment::try_from(input).unwrap();
+        doc.dedup_tags();
+        let tags: Vec<_> = doc
+            .block_tags
+            .iter()
+            .map(|tag| (tag.position, tag.name.as_str()))
+            .collect();
+        assert_eq!(tags, vec![(0, "param"), (1, "param"), (2, "param")]);
+        assert_eq!(
+            doc.block_tags[2].body_items,
+            vec![OwnedBodyItem::TextSegment("foo another foo\n".to_owned())]
+        );
+
+        let mut doc = OwnedDocComment::try_from(input).unwrap();
+        doc.dedup_tags_by_name();
+        assert_eq!(doc.block_tags.len(), 1);
+        assert_eq!(doc.block_tags[0].position, 0);
+
+        let input =
+            "/**\n * @see {@link Foo}\n * @see {@link\n *   Foo }\n * @see {@link Bar}\n */";
+        let mut doc = OwnedDocComment::try_from(input).unwrap();
+        doc.dedup_tags();
+        assert_eq!(doc.block_tags.len(), 2);
+
+        let config = crate::config::ParseConfig {
+            parse_links: true,
+            ..crate::config::ParseConfig::default()
+        };
+        let input = "/**\n * @see {@link Foo the  foo}\n * @see {@link Foo the foo}\n */";
+        let mut doc = OwnedDocComment::from(&crate::parse_with_config(input, config).unwrap());
+        doc.dedup_tags();
+        assert_eq!(doc.block_tags.len(), 1);
+    }
+    #[test]
+    fn test_split_at_tag() {
+        let doc = crate::parse("/** Description.\n * @param foo\n * @param bar */").unwrap();
+
+        let (first, second) = doc.split_at_tag("returns");
+        assert_eq!(first, doc);
+        assert_eq!(second, DocComment::default());
+
+        let (first, second) = doc.split_at_tag("param");
+        assert_eq!(first.description, doc.description);
+        assert!(first.block_tags.is_empty());
+        assert_eq!(second.block_tags, doc.block_tags);
+    }
+
+    #[test]
+    fn test_eq_owned() {
+        let input = "/** Description {@link Foo}\n * @param foo bar\n * @returns */";
+        let doc = crate::parse(input).unwrap();
+        let owned = OwnedDocComment::from(&doc);
+
+        assert_eq!(doc, owned);
+        assert_eq!(owned, doc);
+
+        let mut other = owned.clone();
+        other.block_tags[1].position = 0;
+        assert_ne!(doc, other);
+
+        let mut other = owned.clone();
+        other.description = None;
+        assert_ne!(doc, other);
+
+        let mut other = owned;
+        other.block_tags[0].body_items[0] = OwnedBodyItem::CodeSpan("foo bar\n".to_owned());
+        assert_ne!(other, doc);
+    }
+
+    #[test]
+    fn test_deprecation_message() {
+        let doc = crate::parse("/** @deprecated Use foo */").unwrap();
+        assert!(matches!(
+            doc.deprecation_message(),
+            Some(Cow::Borrowed("Use foo"))
+        ));
+
+        let doc = crate::parse(
+            "/**\n * @since 1.0\n * @deprecated Use foo\n * instead.\n * @deprecated Other\n */",
+        )
+        .unwrap();
+        assert_eq!(
+            doc.deprecation_message().as_deref(),
+            Some("Use foo\ninstead.")
+        );
+    }
+
+    #[test]
+    fn test_tag_counts() {
+        assert_eq!(DocComment::default().block_tag_count(), 0);
+        assert_eq!(DocComment::default().inline_tag_count(), 0);
+
+        let doc = crate::parse(
+            "/**\n * See {@link Foo} and {@link Bar}.\n * @param foo the {@code foo}\n * @returns\n */",
+        )
+        .unwrap();
+        assert_eq!(doc.block_tag_count(), 2);
+        assert_eq!(doc.inline_tag_count(), 3);
+    }
+
+    #[test]
+    fn test_example_tags() {
+        let config = crate::config::ParseConfig {
+            parse_example_titles: true,
+            ..crate::config::ParseConfig::default()
+        };
+        let doc = crate::parse_with_config(
+            "/**\n * @example <caption>Title</caption> foo()\n * bar()\n * @example\n */",
+            config,
+        )
+        .unwrap();
+        assert_eq!(
+            doc.example_tags(),
+            vec![
+                ExampleTag {
+                    title: Some("Title"),
+                    code: Cow::Borrowed("foo()\nbar()"),
+                },
+                ExampleTag::default(),
+            ]
+        );
+
+        let doc = crate::parse("/** @example <caption>Title</caption> foo() */").unwrap();
+        assert_eq!(doc.example_tags()[0].title, None);
+    }
+
+    #[test]
+    fn test_owned_default() {
+        let doc = OwnedDocComment::default();
+        assert!(doc.is_empty());
+        assert_eq!(doc.description, None);
+        assert!(doc.block_tags.is_empty());
+        assert!(OwnedDescription::default().body_items.is_empty());
+
+        let doc = OwnedDocComment {
+            description: Some(OwnedDescription::default()),
+            block_tags: vec![],
+        };
+        assert!(doc.is_empty());
+        assert!(!OwnedDocComment::try_from("/** @returns */")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_structurally_equal_ignoring_deprecated_position() {
+        let doc = crate::parse("/** Foo.\n * @deprecated\n * @param a\n * @param b */").unwrap();
+        let moved = crate::parse("/** Foo.\n * @param a\n * @deprecated\n * @param b */").unwrap();
+        let swapped =
+            crate::parse("/** Foo.\n * @param b\n * @param a\n * @deprecated */").unwrap();
+        let other_description =
+            crate::parse("/** Bar.\n * @param a\n * @param b\n * @deprecated */").unwrap();
+
+        assert!(doc.structurally_equal_ignoring_deprecated_position(&doc));
+        assert!(doc.structurally_equal_ignoring_deprecated_position(&moved));
+        assert!(!doc.structurally_equal_ignoring_deprecated_position(&swapped));
+        assert!(!doc.structurally_equal_ignoring_deprecated_position(&other_description));
+    }
+
+    #[test]
+    fn test_summary_and_details() {
+        assert_eq!(DocComment::default().summary_and_details(), (None, None));
+
+        let doc = crate::parse("/** Single sentence. */").unwrap();
+        assert_eq!(
+            doc.summary_and_details(),
+            (Some("Single sentence.".to_owned()), None)
+        );
+
+        let doc = crate::parse("/** Version 1.0 works! Details? */").unwrap();
+        assert_eq!(
+            doc.summary_and_details(),
+            (
+                Some("Version 1.0 works!".to_owned()),
+                Some("Details?".to_owned())
+            )
+        );
+
+        let doc = crate::parse("/** No sentence end {@link Foo} */").unwrap();
+        assert_eq!(
+            doc.summary_and_details(),
+            (Some("No sentence end Foo".to_owned()), None)
+        );
+
+        let doc = crate::parse("/**\n * See {@link Foo}\n * for details. More here.\n */").unwrap();
+        assert_eq!(
+            doc.summary_and_details(),
+            (
+                Some("See Foo\nfor details.".to_owned()),
+                Some("More here.".to_owned())
+            )
+        );
+
+        let doc = crate::parse("/**\n * See {@link Foo}\n *\n * Details.\n */").unwrap();
+        assert_eq!(
+            doc.summary_and_details(),
+            (Some("See Foo".to_owned()), Some("Details.".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut doc = crate::parse("/** Description.\n * @param foo */").unwrap();
+        let other = crate::parse("/** @param bar\n * @returns baz */").unwrap();
+        doc.extend(other.block_tags.iter().cloned());
+
+        assert_eq!(
+            doc.block_tags
+                .iter()
+                .map(|tag| (tag.position, tag.name))
+                .collect::<Vec<_>>(),
+            vec![(0, "param"), (1, "param"), (2, "returns")]
+        );
+        assert_eq!(doc.block_tags[2].body_items, other.block_tags[1].body_items);
+    }
+
+    #[test]
+    fn test_total_char_count() {
+        assert_eq!(DocComment::default().total_char_count(), 0);
+
+        let doc = crate::parse_with_config(
+            "/**\n * Ünïcode {@code x}\n * @see {@link Foo the foo}\n * @inheritDoc\n */",
+            crate::config::ParseConfig {
+                parse_links: true,
+                parse_code_spans: true,
+                ..crate::config::ParseConfig::default()
+            },
+        )
+        .unwrap();
+        let expected: usize = doc.text_segments().map(|s| s.chars().count()).sum();
+        assert_eq!(doc.total_char_count(), expected);
+        assert_eq!(
+            doc.total_char_count(),
+            "Ünïcode ".chars().count() + 1 + 1 + 7 + 1
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        let item = BodyItem::TextSegment("plain text");
+        assert!(matches!(
+            item.escape_for_html(),
+            Cow::Borrowed("plain text")
+        ));
+        assert!(matches!(
+            item.escape_for_markdown(),
+            Cow::Borrowed("plain text")
+        ));
+
+        let item = BodyItem::TextSegment("<b>*bold*</b> & \\ [x]");
+        assert_eq!(
+            item.escape_for_html(),
+            "&lt;b&gt;*bold*&lt;/b&gt; &amp; \\ [x]"
+        );
+        assert_eq!(
+            item.escape_for_markdown(),
+            "<b>\\*bold\\*</b> & \\\\ \\[x\\]"
+        );
+
+        let item = BodyItem::CodeSpan("a<b");
+        assert_eq!(item.escape_for_html(), "a&lt;b");
+        let item = BodyItem::InlineTag(InlineTag::new("link", "Foo_Bar"));
+        assert_eq!(item.escape_for_markdown(), "Foo\\_Bar");
+    }
+
+    #[test]
+    fn test_param_names() {
+        let doc = crate::parse("/** Description.\n * @returns foo */").unwrap();
+        assert!(doc.param_names().is_empty());
+
+        let doc = crate::parse("/** @param foo description\n * @param bar */").unwrap();
+        assert_eq!(doc.param_names(), vec!["foo", "bar"]);
+
+        let config = crate::config::ParseConfig {
+            parse_type_expressions: true,
+            ..crate::config::ParseConfig::default()
+        };
+        let doc = crate::parse_with_config(
+            "/** @param {string} foo description\n * @param {number} */",
+            config,
+        )
+        .unwrap();
+        assert_eq!(doc.param_names(), vec!["foo"]);
+    }
+
+    #[test]
+    fn test_doc_comment_from() {
+        let doc = crate::parse("/** @param foo\n * @returns bar */").unwrap();
+        assert_eq!(DocComment::from(doc.block_tags.clone()), doc);
+
+        let doc = crate::parse("/** Description. */").unwrap();
+        assert_eq!(DocComment::from(doc.description.clone().unwrap()), doc);
     }
 }
@@ -0,0 +1,81 @@
+//! General purpose [nom](https://docs.rs/nom) combinators used by the doc comment parsers.
+
+use nom::error::{context, VerboseError};
+use nom::{InputLength, Parser};
+
+use crate::parsers;
+
+/// The parser behind the doc comment text, which can be named and stored in struct fields.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::combinators::{take_until_either, Escape, TakeUntilEither, Token};
+/// use nom::Parser;
+///
+/// struct Sentence {
+///     parser: TakeUntilEither<'static>,
+/// }
+///
+/// let mut sentence = Sentence {
+///     parser: take_until_either(&[Token::Escapable(".")], Escape::Char('\\')),
+/// };
+///
+/// assert_eq!(sentence.parser.parse(r"1\.5 apples. Rest"), Ok((". Rest", r"1\.5 apples")));
+/// ```
+pub use crate::parsers::TakeUntilEither;
+pub use crate::parsers::{take_until_either, Escape, Token};
+
+/// Wraps `parser`, failing with [`ErrorKind::NonEmpty`](nom::error::ErrorKind::NonEmpty)
+/// if its output is empty.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::combinators::non_empty;
+/// use nom::bytes::complete::take_while;
+/// use nom::Parser;
+///
+/// let mut digits = non_empty(take_while(|ch: char| ch.is_ascii_digit()));
+///
+/// assert_eq!(digits.parse("42 apples"), Ok((" apples", "42")));
+/// assert!(digits.parse("apples").is_err());
+/// ```
+pub fn non_empty<'a, P, O>(parser: P) -> impl Parser<&'a str, O, VerboseError<&'a str>>
+where
+    P: Parser<&'a str, O, VerboseError<&'a str>>,
+    O: InputLength,
+{
+    parsers::non_empty(parser)
+}
+
+/// Same as [`non_empty`], but `message` is attached to the error as a parser context,
+/// so that it shows up in the output of [`nom::error::convert_error`].
+///
+/// # Examples
+///
+/// ```
+/// use doctor::combinators::verify_non_empty;
+/// use nom::bytes::complete::take_while;
+/// use nom::error::VerboseErrorKind;
+/// use nom::Parser;
+///
+/// let mut digits = verify_non_empty("expected digits", take_while(|ch: char| ch.is_ascii_digit()));
+///
+/// match digits.parse("apples") {
+///     Err(nom::Err::Error(err)) => {
+///         assert_eq!(err.errors.last().unwrap().1, VerboseErrorKind::Context("expected digits"));
+///     }
+///     result => panic!("unexpected result: {:?}", result),
+/// }
+/// ```
+pub fn verify_non_empty<'a, P, O>(
+    message: &'static str,
+    parser: P,
+) -> impl Parser<&'a str, O, VerboseError<&'a str>>
+where
+    P: Parser<&'a str, O, VerboseError<&'a str>>,
+    O: InputLength,
+{
+    context(message, non_empty(parser))
+}
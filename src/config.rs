@@ -0,0 +1,247 @@
+/// Options controlling how doc comments are parsed.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::config::ParseConfig;
+///
+/// let config = ParseConfig {
+///     require_line_leading: false,
+///     ..ParseConfig::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ParseConfig {
+    /// Whether continuation lines are expected to start with a line leading, i.e. ` * `.
+    ///
+    /// When `false`, the line leading is optional on every line, so that comments like
+    /// ```text
+    /// /**
+    /// Description text here.
+    /// @param foo bar
+    /// */
+    /// ```
+    /// can be parsed, including inline tags spanning multiple such lines.
+    ///
+    /// Defaults to `true`.
+    pub require_line_leading: bool,
+
+    /// Whether `{@code expression}` inline tags are parsed as
+    /// [`BodyItem::CodeSpan`](crate::ast::BodyItem::CodeSpan) items.
+    ///
+    /// Only tags whose body fits on a single line are converted,
+    /// multi-line ones are kept as regular inline tags.
+    ///
+    /// Defaults to `false`.
+    pub parse_code_spans: bool,
+
+    /// Whether `@prop` block tags may start with a `{Type}` expression,
+    /// as in `@prop {string} name - description`.
+    ///
+    /// The type expression is kept as the first text segment of the tag body,
+    /// see [`DocComment::prop_tags`](crate::ast::DocComment::prop_tags).
+    ///
+    /// Defaults to `false`.
+    pub parse_prop_tags: bool,
+
+    /// Whether any block tag may start with a `{Type}` expression,
+    /// as in `@param {string} name description`.
+    ///
+    /// The type expression is kept as the first text segment of the tag body.
+    ///
+    /// Defaults to `false`.
+    pub parse_type_expressions: bool,
+
+    /// Whether a `<caption>title</caption>` at the start of an `@example` tag is parsed
+    /// as a separate text segment, even if the code follows it on the same line.
+    ///
+    /// The title is returned by
+    /// [`DocComment::example_tags`](crate::ast::DocComment::example_tags).
+    ///
+    /// Defaults to `false`.
+    pub parse_example_titles: bool,
+
+    /// Whether fenced code blocks, i.e. lines between two lines of three or more backticks,
+    /// are parsed as [`BodyItem::CodeBlock`](crate::ast::BodyItem::CodeBlock) items.
+    /// An optional language may follow the opening fence, as in ` ```rust `.
+    ///
+    /// Unterminated code blocks are parsed as regular text.
+    ///
+    /// Defaults to `false`.
+    pub parse_code_blocks: bool,
+
+    /// Syntax of the accepted tag names.
+    ///
+    /// Defaults to [`TagNameSyntax::Standard`].
+    pub tag_name_syntax: TagNameSyntax,
+
+    /// Whether `{@link target label}` and `{@link target | label}` inline tags are parsed as
+    /// [`BodyItem::Link`](crate::ast::BodyItem::Link) items.
+    ///
+    /// Only tags whose body fits on a single line are converted,
+    /// multi-line ones are kept as regular inline tags.
+    ///
+    /// Defaults to `false`.
+    pub parse_links: bool,
+
+    /// Whether `{@inheritDoc}` inline tags without a body are parsed as
+    /// [`BodyItem::InheritDoc`](crate::ast::BodyItem::InheritDoc) items.
+    ///
+    /// Defaults to `false`.
+    pub parse_inherit_doc: bool,
+
+    /// Character escaping `{`, `}` and `@` in bodies, e.g. `\{` for a literal `{`.
+    /// The escape sequences are kept in the parsed text as they are.
+    ///
+    /// Ignored if [`ParseConfig::use_doubling_escape`] is enabled.
+    ///
+    /// Defaults to `'\\'`.
+    pub escape_char: char,
+
+    /// Whether `{`, `}` and `@` are escaped by doubling them, e.g. `{{` for a literal `{`,
+    /// instead of with [`ParseConfig::escape_char`].
+    /// The escape sequences are kept in the parsed text as they are.
+    ///
+    /// Defaults to `false`.
+    pub use_doubling_escape: bool,
+
+    /// Whether only `{@` starts an inline tag in a body.
+    ///
+    /// If enabled, braces not followed by `@`, e.g. in `returns {}` or `{ a: 1 }`,
+    /// are parsed as text and don't need to be escaped. The downside is that a typo like
+    /// `{link Foo}` silently ends up in the text instead of failing the parsing.
+    /// If disabled, every unescaped brace in a body has to belong to an inline tag,
+    /// otherwise the parsing fails.
+    ///
+    /// Defaults to `true`.
+    pub strict_inline_brace: bool,
+
+    /// Whether KDoc `[Foo]` reference links in bodies are parsed as
+    /// [`BodyItem::Link`](crate::ast::BodyItem::Link) items without a label.
+    /// Escaped brackets, e.g. `\[Foo]`, are kept as text.
+    ///
+    /// Defaults to `false`.
+    pub parse_kdoc_links: bool,
+
+    /// How many levels of inline tags nested in the body of each other are parsed,
+    /// counting the outermost tag, e.g. `2` for `{@link Foo {@code bar}}`.
+    ///
+    /// Nested tags are kept as text in the [`body_lines`](crate::ast::InlineTag::body_lines)
+    /// of the outermost tag.
+    /// Tags nested deeper than that are kept as text without being parsed,
+    /// so values below `2` disable nesting.
+    /// A `{` in the body of an inline tag that doesn't start a nested tag has to be escaped.
+    ///
+    /// Defaults to `1`.
+    pub max_inline_tag_depth: usize,
+
+    /// Whether comments may also start with `/*!`, as supported by Doxygen.
+    ///
+    /// Defaults to `false`.
+    pub allow_bang_comment_start: bool,
+
+    /// Whether block tags may also be written as `\name`, as supported by Doxygen.
+    /// Both `@name` and `\name` tags are parsed into tags named `name`.
+    ///
+    /// A backslash followed by a letter always starts a tag, even if it's
+    /// the [`escape_char`](ParseConfig::escape_char).
+    ///
+    /// Defaults to `false`.
+    pub allow_backslash_tags: bool,
+}
+
+impl Default for ParseConfig {
+    fn default() -> Self {
+        Self {
+            require_line_leading: true,
+            parse_code_spans: false,
+            parse_prop_tags: false,
+            parse_type_expressions: false,
+            parse_example_titles: false,
+            parse_code_blocks: false,
+            tag_name_syntax: TagNameSyntax::default(),
+            parse_links: false,
+            parse_inherit_doc: false,
+            escape_char: '\\',
+            use_doubling_escape: false,
+            strict_inline_brace: true,
+            parse_kdoc_links: false,
+            max_inline_tag_depth: 1,
+            allow_bang_comment_start: false,
+            allow_backslash_tags: false,
+        }
+    }
+}
+
+/// Syntax of the tag names accepted by the parser.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum TagNameSyntax {
+    /// An ASCII letter followed by letters, digits and `_`, e.g. `@myTag_1`.
+    #[default]
+    Standard,
+    /// Like `Standard`, but names may also start with `_` and contain `-`,
+    /// e.g. `@_internal` or `@my-tag`, as allowed by TSDoc.
+    Extended,
+    /// ASCII letters only, e.g. `@myTag`, as in classic JavaDoc.
+    Alphabetic,
+}
+
+/// Options controlling how doc comments are formatted by
+/// [`format_doc_comment`](crate::render::format::format_doc_comment).
+///
+/// # Examples
+///
+/// ```
+/// use doctor::config::FormatConfig;
+///
+/// let config = FormatConfig {
+///     indent: "    ".to_owned(),
+///     max_line_length: Some(80),
+///     ..FormatConfig::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct FormatConfig {
+    /// Indentation prepended to every line of the comment.
+    ///
+    /// Defaults to no indentation.
+    pub indent: String,
+
+    /// Maximum length of a line, including the indentation and the line leading.
+    /// Longer description lines are wrapped at word boundaries.
+    ///
+    /// Defaults to `None`, i.e. lines are never wrapped.
+    pub max_line_length: Option<usize>,
+
+    /// Whether block tags are reordered according to `tag_order`.
+    ///
+    /// Defaults to `false`.
+    pub sort_tags: bool,
+
+    /// Names of the block tags in the order they're emitted in when `sort_tags` is `true`.
+    /// Tags missing from the list go last, keeping their relative order.
+    pub tag_order: Vec<String>,
+}
+
+/// Information about where a doc comment comes from, used to enrich parse errors.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::config::ParseContext;
+///
+/// let context = ParseContext {
+///     source_file: Some("src/index.ts".to_owned()),
+///     line_offset: 41,
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ParseContext {
+    /// Path of the file the comment was extracted from, if any.
+    pub source_file: Option<String>,
+
+    /// Number of lines preceding the comment in the source file.
+    /// Line numbers in error messages are shifted by this amount.
+    pub line_offset: usize,
+}
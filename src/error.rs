@@ -1,17 +1,54 @@
+use std::borrow::Cow;
 use std::fmt::{Display, Formatter, Result};
 
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq)]
 pub enum Error {
     ParseError(String),
+    IoError(String),
+    MultipleErrors(Vec<Self>),
+}
+
+impl Error {
+    /// Returns the full error message.
+    ///
+    /// Unlike the [`Display`] output, which only has the first line of each error,
+    /// parse errors include every parser context along with the location in the input.
+    /// Messages of multiple errors are separated with a line break.
+    #[must_use]
+    pub fn verbose_message(&self) -> Cow<'_, str> {
+        match self {
+            Self::ParseError(msg) | Self::IoError(msg) => Cow::Borrowed(msg),
+            Self::MultipleErrors(errors) => Cow::Owned(
+                errors
+                    .iter()
+                    .map(Self::verbose_message)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+        }
+    }
 }
 
 impl std::error::Error for Error {}
 
 impl Display for Error {
+    /// Writes the first line of the error message, e.g. `0: at line 1, in Tag:` for parse errors.
+    /// Use [`Error::verbose_message`] for the full message.
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
-            Self::ParseError(msg) => write!(f, "{}", msg),
+            Self::ParseError(msg) | Self::IoError(msg) => {
+                write!(f, "{}", msg.lines().next().unwrap_or_default())
+            }
+            Self::MultipleErrors(errors) => {
+                for (index, err) in errors.iter().enumerate() {
+                    if index > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{err}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -24,6 +61,23 @@ mod tests {
 
     #[test]
     fn test_implement_error() {
-        assert_error::<Error>()
+        assert_error::<Error>();
+    }
+
+    #[test]
+    fn test_display() {
+        let err = crate::parse("/** {@} */").unwrap_err();
+        let verbose = err.verbose_message();
+
+        assert!(verbose.lines().count() > 1);
+        assert_eq!(err.to_string(), verbose.lines().next().unwrap());
+        assert_eq!(err.to_string(), "0: at line 1, in Tag:");
+
+        let errors = Error::MultipleErrors(vec![
+            Error::ParseError("first\ndetails".to_owned()),
+            Error::IoError("second".to_owned()),
+        ]);
+        assert_eq!(errors.to_string(), "first\nsecond");
+        assert_eq!(errors.verbose_message(), "first\ndetails\nsecond");
     }
 }
@@ -0,0 +1,88 @@
+//! Extraction of doc comments from source files.
+
+use std::fs;
+use std::path::Path;
+
+use crate::ast::OwnedDocComment;
+use crate::error::Error;
+use crate::{parse, scanner};
+
+/// Reads the file at `path` and parses all the doc comments it contains.
+///
+/// Returns every successfully parsed comment along with the byte offset of its `/**` opener.
+/// Comments that fail to parse are skipped.
+///
+/// # Errors
+///
+/// Returns [`Error::IoError`] if the file can't be read and [`Error::MultipleErrors`]
+/// with the errors of every comment if none of the file's doc comments could be parsed.
+pub fn parse_file(path: &Path) -> Result<Vec<(usize, OwnedDocComment)>, Error> {
+    let source = fs::read_to_string(path).map_err(|err| Error::IoError(err.to_string()))?;
+
+    let mut comments = vec![];
+    let mut errors = vec![];
+    for (offset, comment) in scanner::doc_comments(&source) {
+        match parse(comment) {
+            Ok(doc) => comments.push((offset, OwnedDocComment::from(&doc))),
+            Err(err) => errors.push(err),
+        }
+    }
+
+    if comments.is_empty() && !errors.is_empty() {
+        Err(Error::MultipleErrors(errors))
+    } else {
+        Ok(comments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn write_temp_file(name: &str, contents: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("doctor-{}-{}", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_parse_file() {
+        let path = write_temp_file(
+            "parse_file.js",
+            "/** First */\nfunction foo() {}\n/** Invalid {@} */\n/**\n * Second\n */\nfunction bar() {}\n",
+        );
+        let result = parse_file(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            result,
+            Ok(vec![
+                (0, OwnedDocComment::from(&parse("/** First */").unwrap())),
+                (
+                    50,
+                    OwnedDocComment::from(&parse("/**\n * Second\n */").unwrap())
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_file_all_invalid() {
+        let path = write_temp_file("parse_file_all_invalid.js", "/** {@} */\n/** {@} */\n");
+        let result = parse_file(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(Error::MultipleErrors(errors)) if errors.len() == 2));
+    }
+
+    #[test]
+    fn test_parse_file_missing() {
+        assert!(matches!(
+            parse_file(Path::new("/this/file/does/not/exist.js")),
+            Err(Error::IoError(_))
+        ));
+    }
+}
@@ -42,14 +42,17 @@
 //!         block_tags: vec![
 //!             BlockTag {
 //!                 name: "blockTag1",
+//!                 position: 0,
 //!                 body_items: vec![]
 //!             },
 //!             BlockTag {
 //!                 name: "blockTag2",
+//!                 position: 1,
 //!                 body_items: vec![BodyItem::TextSegment("with body text\n"),]
 //!             },
 //!             BlockTag {
 //!                 name: "blockTag3",
+//!                 position: 2,
 //!                 body_items: vec![
 //!                     BodyItem::TextSegment("with body text and "),
 //!                     BodyItem::InlineTag(InlineTag {
@@ -65,6 +68,25 @@
 //! ```
 //! For additional info check the [documentation](https://docs.rs/doctor).
 //!
+//! ## ⬆️ Migrating from 0.3
+//!
+//! `ast::BlockTag` and `ast::OwnedBlockTag` have a new
+//! `position` field holding the 0-based index of the tag within the doc comment's `block_tags`.
+//! The parser fills it in, but struct literals written by hand now have to set it, e.g.
+//!
+//! ```rust
+//! use doctor::ast::BlockTag;
+//!
+//! let tag = BlockTag {
+//!     name: "param",
+//!     position: 0,
+//!     body_items: vec![],
+//! };
+//! ```
+//!
+//! Patterns destructuring a `BlockTag` need to list `position` or end with `..`.
+//! With the `serde` feature, `position` is required when deserializing tags as well.
+//!
 //! ## 🔮 Design Goals
 //!
 //! - The crate is agnostic from the concrete set of valid tags so that more high-level parsers (JSDoc, TSDoc, etc.) can be built on top of it.
@@ -79,14 +101,38 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery, clippy::cargo)]
 
 pub mod ast;
+#[cfg(feature = "parsers")]
+pub mod combinators;
+pub mod config;
 pub mod error;
+#[cfg(feature = "io")]
+pub mod io;
+#[cfg(feature = "openapi")]
+pub mod openapi;
 mod parsers;
+pub mod render;
+mod scanner;
+pub mod spanned;
+pub mod style;
+#[cfg(feature = "proc-macro")]
+mod tokens;
+pub mod warning;
 
-use nom::error::convert_error;
+use std::convert::TryFrom;
+
+use nom::combinator::all_consuming;
+#[cfg(feature = "parsers")]
+use nom::error::VerboseError;
+use nom::error::{context, convert_error};
 use nom::Finish;
+#[cfg(feature = "parsers")]
+use nom::IResult;
 
-use ast::DocComment;
+use ast::{BodyItem, DocComment, OwnedDocComment};
+use config::{ParseConfig, ParseContext};
 use error::Error;
+use spanned::SpannedDocComment;
+use warning::Warning;
 
 #[cfg(feature = "serde")]
 #[macro_use]
@@ -126,14 +172,17 @@ extern crate serde;
 ///         block_tags: vec![
 ///             BlockTag {
 ///                 name: "blockTag1",
+///                 position: 0,
 ///                 body_items: vec![]
 ///             },
 ///             BlockTag {
 ///                 name: "blockTag2",
+///                 position: 1,
 ///                 body_items: vec![BodyItem::TextSegment("with body text\n"),]
 ///             },
 ///             BlockTag {
 ///                 name: "blockTag3",
+///                 position: 2,
 ///                 body_items: vec![
 ///                     BodyItem::TextSegment("with body text and "),
 ///                     BodyItem::InlineTag(InlineTag {
@@ -152,23 +201,507 @@ extern crate serde;
 ///
 /// If `input` is not a valid doc comment, an error explaining where the parsing failed is returned.  
 ///
-pub fn parse(input: &str) -> Result<DocComment, Error> {
-    parsers::doc_comment(input)
+pub fn parse(input: &str) -> Result<DocComment<'_>, Error> {
+    parse_with_config(input, ParseConfig::default())
+}
+
+/// Parses `input` into a `DocComment` struct using the provided `config`.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse_with_config;
+/// use doctor::ast::{DocComment, Description, BodyItem, BlockTag};
+/// use doctor::config::ParseConfig;
+///
+/// let config = ParseConfig {
+///     require_line_leading: false,
+///     ..ParseConfig::default()
+/// };
+///
+/// assert_eq!(
+///     parse_with_config("/**\nDescription text here.\n@param foo bar\n*/", config),
+///     Ok(DocComment {
+///         description: Some(Description {
+///             body_items: vec![BodyItem::TextSegment("Description text here.\n")]
+///         }),
+///         block_tags: vec![BlockTag {
+///             name: "param",
+///             position: 0,
+///             body_items: vec![BodyItem::TextSegment("foo bar\n")]
+///         }],
+///     }),
+/// );
+/// ```
+///
+/// # Errors
+///
+/// If `input` is not a valid doc comment, an error explaining where the parsing failed is returned.
+///
+pub fn parse_with_config(input: &str, config: ParseConfig) -> Result<DocComment<'_>, Error> {
+    parsers::doc_comment(config)(input)
+        .finish()
+        .map(|(_, doc)| doc)
+        .map_err(|err| Error::ParseError(convert_error(input, err)))
+}
+
+/// Parses `input` like [`parse`], reporting errors relative to the source file described by `ctx`.
+///
+/// Line numbers in the error message are shifted by `ctx.line_offset`. When `ctx.source_file` is
+/// set, the message starts with an `error in <file>:<line>: <reason>` line, e.g.
+/// `error in index.ts:13: in Tag`, which is also what the error displays.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse_with_context;
+/// use doctor::config::ParseContext;
+///
+/// let ctx = ParseContext {
+///     source_file: Some("index.ts".to_owned()),
+///     line_offset: 10,
+/// };
+/// let err = parse_with_context("/**\n * Description\n * {@} */", ctx).unwrap_err();
+///
+/// assert_eq!(err.to_string(), "error in index.ts:13: in Tag");
+/// ```
+///
+/// # Errors
+///
+/// If `input` is not a valid doc comment, an error explaining where the parsing failed is returned.
+///
+pub fn parse_with_context(input: &str, ctx: ParseContext) -> Result<DocComment<'_>, Error> {
+    parsers::doc_comment(ParseConfig::default())(input)
+        .finish()
+        .map(|(_, doc)| doc)
+        .map_err(|err| {
+            let line = err.errors.first().map_or(1, |(rest, _)| {
+                input[..input.len() - rest.len()].matches('\n').count() + 1
+            }) + ctx.line_offset;
+            let message = shift_line_numbers(&convert_error(input, err), ctx.line_offset);
+
+            Error::ParseError(match ctx.source_file {
+                Some(file) => {
+                    // The reason of the first error, e.g. `in Tag` of `0: at line 1, in Tag:`.
+                    let reason = message
+                        .lines()
+                        .next()
+                        .and_then(|header| header.split_once(", "))
+                        .map_or("", |(_, reason)| reason.trim_end_matches(':'));
+                    format!("error in {file}:{line}: {reason}\n{message}")
+                }
+                None => message,
+            })
+        })
+}
+
+/// Adds `offset` to the line number of every `<n>: at line <N>,` header line of a
+/// [`convert_error`] `message`, leaving the echoed input lines intact.
+fn shift_line_numbers(message: &str, offset: usize) -> String {
+    message
+        .split_inclusive('\n')
+        .map(|line| shift_header_line(line, offset).unwrap_or_else(|| line.to_owned()))
+        .collect()
+}
+
+/// Adds `offset` to the line number of `line` if it's a `<n>: at line <N>,` header line.
+fn shift_header_line(line: &str, offset: usize) -> Option<String> {
+    const PREFIX: &str = ": at line ";
+
+    let index = line.find(PREFIX)?;
+    if index == 0 || !line[..index].bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (before, after) = line.split_at(index + PREFIX.len());
+    let digits = after
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(after.len());
+    let number = after[..digits].parse::<usize>().ok()?;
+    Some(format!("{before}{}{}", number + offset, &after[digits..]))
+}
+
+/// Applies `preprocess` to `input` and parses the result with [`parse`],
+/// e.g. to strip proprietary annotations or replace template placeholders beforehand.
+///
+/// As the preprocessed text is a new string, an owned doc comment is returned.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::{parse, parse_with_preprocessor};
+///
+/// let doc = parse_with_preprocessor("/** Returns {{name}}. */", |input| {
+///     input.replace("{{name}}", "the user")
+/// })
+/// .unwrap();
+///
+/// assert_eq!(doc, parse("/** Returns the user. */").unwrap());
+/// ```
+///
+/// # Errors
+///
+/// If the preprocessed input is not a valid doc comment, an error explaining where the parsing
+/// failed is returned.
+///
+pub fn parse_with_preprocessor<F>(input: &str, preprocess: F) -> Result<OwnedDocComment, Error>
+where
+    F: Fn(&str) -> String,
+{
+    OwnedDocComment::try_from(preprocess(input))
+}
+
+/// Parses `input` like [`parse`], also returning the non-fatal issues found in the comment,
+/// such as a missing description or block tags with an empty body.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse_checked;
+/// use doctor::warning::WarningKind;
+///
+/// let (doc, warnings) = parse_checked("/** @returns */").unwrap();
+///
+/// assert_eq!(doc.block_tags.len(), 1);
+/// assert_eq!(
+///     warnings.iter().map(|warning| warning.kind).collect::<Vec<_>>(),
+///     vec![WarningKind::MissingDescription, WarningKind::EmptyTagBody]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// If `input` is not a valid doc comment, an error explaining where the parsing failed is returned.
+///
+pub fn parse_checked(input: &str) -> Result<(DocComment<'_>, Vec<Warning>), Error> {
+    parse(input).map(|doc| {
+        let warnings = warning::check(&doc);
+        (doc, warnings)
+    })
+}
+
+/// Parses every comment in `inputs`, returning the results in the same order.
+///
+/// Each entry is parsed independently and no state is shared between them,
+/// so callers can split `inputs` into chunks and parse them in parallel,
+/// e.g. with `rayon`'s `par_chunks`, concatenating the results afterwards.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::{parse, parse_batch};
+///
+/// let results = parse_batch(&["/** First. */", "not a comment"]);
+///
+/// assert_eq!(results.len(), 2);
+/// assert_eq!(results[0], parse("/** First. */"));
+/// assert!(results[1].is_err());
+/// ```
+#[must_use]
+pub fn parse_batch<'a>(inputs: &[&'a str]) -> Vec<Result<DocComment<'a>, Error>> {
+    inputs.iter().map(|input| parse(input)).collect()
+}
+
+/// Finds every doc comment in `input`, e.g. a source file, and parses it.
+///
+/// Returns the byte offset of each comment's `/**` in `input` along with the parse result.
+/// String literals and regular comments are skipped, so that `/**` sequences inside of them
+/// aren't mistaken for doc comments.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::{parse, parse_many_with_offsets};
+///
+/// let source = "/** Foo. */\nstruct Foo;\n\n/** Bar. */\nstruct Bar;";
+///
+/// assert_eq!(
+///     parse_many_with_offsets(source),
+///     vec![(0, parse("/** Foo. */")), (25, parse("/** Bar. */"))]
+/// );
+/// ```
+#[must_use]
+pub fn parse_many_with_offsets(input: &str) -> Vec<(usize, Result<DocComment<'_>, Error>)> {
+    scanner::doc_comments(input)
+        .into_iter()
+        .map(|(offset, comment)| (offset, parse(comment)))
+        .collect()
+}
+
+/// Parses `input` into a `DocComment` using `start` and `end` as the comment delimiters
+/// instead of `/**` and `*/`.
+///
+/// Everything between the delimiters is parsed the same way as in [`parse`].
+/// `input` has to end with `end`.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::{parse, parse_with_custom_start_end};
+///
+/// assert_eq!(
+///     parse_with_custom_start_end("<!--\n * Description.\n * @param foo\n -->", "<!--", "-->"),
+///     parse("/**\n * Description.\n * @param foo\n */"),
+/// );
+/// ```
+///
+/// # Errors
+///
+/// If `input` is not a valid doc comment, an error explaining where the parsing failed is returned.
+///
+pub fn parse_with_custom_start_end<'a>(
+    input: &'a str,
+    start: &str,
+    end: &str,
+) -> Result<DocComment<'a>, Error> {
+    parsers::delimited_doc_comment(ParseConfig::default(), start, end, input)
         .finish()
         .map(|(_, doc)| doc)
         .map_err(|err| Error::ParseError(convert_error(input, err)))
 }
 
+/// Parses `input` as the body of a description or a block tag, e.g. when building
+/// a parser for a custom doc comment syntax on top of this crate.
+///
+/// `input` must not contain the comment delimiters, but may have line leadings.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse_body;
+/// use doctor::ast::{BodyItem, InlineTag};
+///
+/// assert_eq!(
+///     parse_body("the {@link Foo} instance"),
+///     Ok(vec![
+///         BodyItem::TextSegment("the "),
+///         BodyItem::InlineTag(InlineTag::new("link", "Foo")),
+///         BodyItem::TextSegment("instance"),
+///     ])
+/// );
+/// ```
+///
+/// # Errors
+///
+/// If `input` is blank or not a valid body, e.g. it contains a block tag,
+/// an error explaining where the parsing failed is returned.
+///
+pub fn parse_body(input: &str) -> Result<Vec<BodyItem<'_>>, Error> {
+    context("body", all_consuming(parsers::body(ParseConfig::default())))(input)
+        .finish()
+        .map(|(_, body_items)| body_items)
+        .map_err(|err| Error::ParseError(convert_error(input, err)))
+}
+
+/// Parses a fragment of plain text, e.g. a description stored outside of a comment,
+/// into the body items of a doc comment.
+///
+/// Unlike [`parse_body`], the lines of `input` aren't expected to have line leadings
+/// and blank input results in no items.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse_snippet;
+/// use doctor::ast::{BodyItem, InlineTag};
+///
+/// assert_eq!(
+///     parse_snippet("Returns the\n{@link Foo} instance"),
+///     Ok(vec![
+///         BodyItem::TextSegment("Returns the\n"),
+///         BodyItem::InlineTag(InlineTag::new("link", "Foo")),
+///         BodyItem::TextSegment("instance"),
+///     ])
+/// );
+/// assert_eq!(parse_snippet(""), Ok(vec![]));
+/// ```
+///
+/// # Errors
+///
+/// If `input` is not a valid body, e.g. it contains a block tag or an unterminated inline tag,
+/// an error explaining where the parsing failed is returned.
+///
+pub fn parse_snippet(input: &str) -> Result<Vec<BodyItem<'_>>, Error> {
+    if input.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let config = ParseConfig {
+        require_line_leading: false,
+        ..ParseConfig::default()
+    };
+    context("snippet", all_consuming(parsers::body(config)))(input)
+        .finish()
+        .map(|(_, body_items)| body_items)
+        .map_err(|err| Error::ParseError(convert_error(input, err)))
+}
+
+/// Parses `input` into a `SpannedDocComment`, recording where the tags are located in `input`.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse_spanned;
+///
+/// let input = "/** @param foo {@link Foo} */";
+/// let doc = parse_spanned(input).unwrap();
+///
+/// assert_eq!(&input[doc.block_tags[0].name_range.clone()], "param");
+/// assert_eq!(&input[doc.block_tags[0].inline_tags[0].name_range.clone()], "link");
+/// ```
+///
+/// # Errors
+///
+/// If `input` is not a valid doc comment, an error explaining where the parsing failed is returned.
+///
+pub fn parse_spanned(input: &str) -> Result<SpannedDocComment<'_>, Error> {
+    parse(input).map(|doc| SpannedDocComment::new(input, doc))
+}
+
+/// [nom](https://docs.rs/nom) parser consuming the doc comment start sequence, i.e. `/**`,
+/// along with the whitespace and line ending following it.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::nom_comment_start;
+///
+/// assert_eq!(nom_comment_start("/** Description */"), Ok(("Description */", ())));
+/// assert!(nom_comment_start("/* Comment */").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Fails if `i` doesn't start with `/**`.
+#[cfg(feature = "parsers")]
+pub fn nom_comment_start(i: &str) -> IResult<&str, (), VerboseError<&str>> {
+    parsers::comment_start(i)
+}
+
+/// [nom](https://docs.rs/nom) parser consuming the doc comment end sequence, i.e. `*/`,
+/// along with the whitespace preceding it.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::nom_comment_end;
+///
+/// assert_eq!(nom_comment_end("\n */ fn foo() {}"), Ok((" fn foo() {}", ())));
+/// assert!(nom_comment_end("Description */").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Fails if `i` doesn't start with `*/`, optionally preceded by whitespace.
+#[cfg(feature = "parsers")]
+pub fn nom_comment_end(i: &str) -> IResult<&str, (), VerboseError<&str>> {
+    parsers::comment_end(i)
+}
+
+/// [nom](https://docs.rs/nom) parser consuming the line leading of a doc comment line,
+/// i.e. the `*` along with the whitespace around it, and returning the consumed input.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::nom_line_leading;
+///
+/// assert_eq!(nom_line_leading("  * Description"), Ok(("Description", "  * ")));
+/// assert!(nom_line_leading(" */").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Fails if `i` doesn't start with `*`, optionally preceded by whitespace,
+/// or if it starts with the comment end sequence.
+#[cfg(feature = "parsers")]
+pub fn nom_line_leading(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+    parsers::line_leading(i)
+}
+
+/// [nom](https://docs.rs/nom) parser consuming a tag name prefixed with `@`,
+/// returning the name without the prefix.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::nom_tag_name;
+///
+/// assert_eq!(nom_tag_name("@param foo"), Ok((" foo", "param")));
+/// assert!(nom_tag_name("param foo").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Fails if `i` doesn't start with `@` followed by a letter.
+#[cfg(feature = "parsers")]
+pub fn nom_tag_name(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+    parsers::tag_name(i)
+}
+
+/// Parses `input` into a `DocComment`, salvaging as much of it as possible when it's invalid.
+///
+/// Unlike [`parse`], a malformed line doesn't fail the whole comment:
+/// the rest of the offending line is skipped and parsing continues on the next one.
+/// `handler` is called with every error encountered along the way.
+///
+/// Returns `None` if `input` couldn't be parsed as a doc comment at all.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse_with_error_handler;
+/// use doctor::ast::{DocComment, Description, BodyItem, BlockTag};
+///
+/// let mut errors = vec![];
+/// assert_eq!(
+///     parse_with_error_handler(
+///         r#"/**
+///         * This is a doc comment.
+///         * This {@} line can't be fully parsed.
+///         * @blockTag with body text
+///         */"#,
+///         |err| errors.push(err),
+///     ),
+///     Some(DocComment {
+///         description: Some(Description {
+///             body_items: vec![
+///                 BodyItem::TextSegment("This is a doc comment.\n"),
+///                 BodyItem::TextSegment("This "),
+///             ]
+///         }),
+///         block_tags: vec![BlockTag {
+///             name: "blockTag",
+///             position: 0,
+///             body_items: vec![BodyItem::TextSegment("with body text\n")]
+///         }],
+///     }),
+/// );
+/// assert_eq!(errors.len(), 1);
+/// ```
+pub fn parse_with_error_handler<F>(input: &str, mut handler: F) -> Option<DocComment<'_>>
+where
+    F: FnMut(Error),
+{
+    if let Ok(doc) = parse(input) {
+        return Some(doc);
+    }
+
+    parsers::doc_comment_with_recovery(ParseConfig::default(), input, |err| {
+        handler(Error::ParseError(convert_error(input, err)));
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ast::{BlockTag, Description, InlineTag};
 
     #[test]
     fn test_parse_invalid() {
         assert_eq!(
             parse("/** Comment */ not comment"),
             Err(Error::ParseError(
-                r#"0: at line 1, in Eof:
+                r"0: at line 1, in Eof:
 /** Comment */ not comment
               ^
 
@@ -176,9 +709,249 @@ mod tests {
 /** Comment */ not comment
 ^
 
-"#
+"
                 .to_owned()
             ))
-        )
+        );
+    }
+    #[test]
+    fn test_parse_escaped_comment_end() {
+        assert_eq!(
+            parse("/** Ends here \\*/"),
+            Ok(DocComment {
+                description: Some(Description {
+                    body_items: vec![BodyItem::TextSegment("Ends here \\")]
+                }),
+                block_tags: vec![]
+            })
+        );
+        assert!(parse("/** Ends here \\*/ not comment */").is_err());
+    }
+
+    #[test]
+    fn test_parse_many_with_offsets() {
+        let source = r#"
+            /** Description. */
+            fn foo() {
+                let s = "/** not a comment */"; // /** nor this */
+            }
+            /* /** nor this */
+            /** @param {@} broken */
+            /**/
+            /** Unterminated"#;
+        let results = parse_many_with_offsets(source);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(&source[results[0].0..][..3], "/**");
+        assert_eq!(results[0].1, parse("/** Description. */"));
+        assert!(results[1].1.is_err());
+        assert!(results[2].1.is_err());
+        assert_eq!(parse_many_with_offsets("no comments"), vec![]);
+
+        let results = parse_many_with_offsets("let c = '\"'; /** Doc A. */\n/** Doc B. */");
+        assert_eq!(
+            results.into_iter().map(|(_, doc)| doc).collect::<Vec<_>>(),
+            vec![parse("/** Doc A. */"), parse("/** Doc B. */")]
+        );
+    }
+
+    #[test]
+    fn test_parse_body() {
+        assert_eq!(
+            parse_body("First line\n * second {@code line}"),
+            Ok(vec![
+                BodyItem::TextSegment("First line\n"),
+                BodyItem::TextSegment("second "),
+                BodyItem::InlineTag(InlineTag::new("code", "line")),
+            ])
+        );
+        assert!(parse_body("").is_err());
+        assert!(parse_body("  \n ").is_err());
+        assert!(parse_body("text\n * @param foo").is_err());
+    }
+
+    #[test]
+    fn test_parse_snippet() {
+        assert_eq!(parse_snippet(" \n "), Ok(vec![]));
+        assert_eq!(
+            parse_snippet("First line\nsecond {@code x}"),
+            Ok(vec![
+                BodyItem::TextSegment("First line\n"),
+                BodyItem::TextSegment("second "),
+                BodyItem::InlineTag(InlineTag::new("code", "x")),
+            ])
+        );
+        assert!(parse_snippet("Text {@link Foo").is_err());
+        assert!(parse_snippet("Text\n@param foo").is_err());
+    }
+
+    #[test]
+    fn test_parse_batch() {
+        let inputs = [
+            "/** First. */",
+            "/** @param foo */",
+            "/* not a doc comment */",
+        ];
+        let results = parse_batch(&inputs);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0], parse(inputs[0]));
+        assert_eq!(results[1], parse(inputs[1]));
+        assert_eq!(results[2], parse(inputs[2]));
+        assert!(results[2].is_err());
+        assert_eq!(
+            inputs
+                .chunks(2)
+                .flat_map(|chunk| parse_batch(chunk))
+                .collect::<Vec<_>>(),
+            results
+        );
+        assert_eq!(parse_batch(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_parse_with_custom_start_end() {
+        assert_eq!(
+            parse_with_custom_start_end("{-- Description {@link Foo}. --}", "{--", "--}"),
+            parse("/** Description {@link Foo}. */")
+        );
+        assert_eq!(
+            parse_with_custom_start_end("<!-- @param foo bar -->", "<!--", "-->"),
+            parse("/** @param foo bar */")
+        );
+        assert_eq!(
+            parse_with_custom_start_end("<!---->", "<!--", "-->"),
+            parse("/***/")
+        );
+        assert!(parse_with_custom_start_end("/** Description */", "<!--", "-->").is_err());
+        assert!(parse_with_custom_start_end("<!-- Description", "<!--", "-->").is_err());
+        assert!(
+            parse_with_custom_start_end("<!-- Description --> not comment", "<!--", "-->").is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_with_context() {
+        let input = "/**\n * Description\n * {@} */";
+        assert_eq!(
+            parse_with_context("/** Comment */", ParseContext::default()),
+            parse("/** Comment */")
+        );
+        assert_eq!(
+            parse_with_context(input, ParseContext::default()),
+            parse(input)
+        );
+
+        let ctx = ParseContext {
+            source_file: None,
+            line_offset: 5,
+        };
+        let message = parse_with_context(input, ctx)
+            .unwrap_err()
+            .verbose_message()
+            .into_owned();
+        assert!(message.starts_with("0: at line 8, in Tag:"));
+        assert_eq!(
+            message.lines().count(),
+            parse(input).unwrap_err().verbose_message().lines().count()
+        );
+
+        let ctx = ParseContext {
+            source_file: Some("src/index.ts".to_owned()),
+            line_offset: 5,
+        };
+        let message = parse_with_context(input, ctx)
+            .unwrap_err()
+            .verbose_message()
+            .into_owned();
+        assert!(message.starts_with("error in src/index.ts:8: in Tag\n0: at line 8, in Tag:"));
+
+        // Echoed input lines are kept as they are, so that the caret stays in place.
+        let input = "/**\n * at line 1 {@} */";
+        let ctx = ParseContext {
+            source_file: None,
+            line_offset: 100,
+        };
+        let message = parse_with_context(input, ctx)
+            .unwrap_err()
+            .verbose_message()
+            .into_owned();
+        assert_eq!(
+            message
+                .replace("at line 101,", "at line 1,")
+                .replace("at line 102,", "at line 2,"),
+            parse(input).unwrap_err().verbose_message()
+        );
+    }
+
+    #[test]
+    fn test_parse_with_preprocessor() {
+        let strip = |input: &str| input.replace("@internal-annotation ", "");
+        assert_eq!(
+            parse_with_preprocessor("/** @internal-annotation Comment */", strip),
+            Ok(OwnedDocComment::from(&parse("/** Comment */").unwrap()))
+        );
+        assert!(parse_with_preprocessor("/** Comment */", |_| String::new()).is_err());
+    }
+
+    #[test]
+    fn test_parse_with_error_handler() {
+        let mut errors = vec![];
+        assert_eq!(
+            parse_with_error_handler("/** Comment */", |err| errors.push(err)),
+            parse("/** Comment */").ok()
+        );
+        assert_eq!(errors, vec![]);
+
+        assert_eq!(
+            parse_with_error_handler("/** Comment */ not comment", |err| errors.push(err)),
+            parse("/** Comment */").ok()
+        );
+        assert_eq!(errors.len(), 1);
+
+        errors.clear();
+        assert_eq!(
+            parse_with_error_handler(
+                r"/**
+                * Description {@} with a broken line.
+                *
+                * @param foo {@}
+                * @param bar
+                */",
+                |err| errors.push(err)
+            ),
+            Some(DocComment {
+                description: Some(Description {
+                    body_items: vec![BodyItem::TextSegment("Description ")]
+                }),
+                block_tags: vec![
+                    BlockTag {
+                        name: "param",
+                        position: 0,
+                        body_items: vec![BodyItem::TextSegment("foo ")]
+                    },
+                    BlockTag {
+                        name: "param",
+                        position: 1,
+                        body_items: vec![BodyItem::TextSegment("bar\n")]
+                    },
+                ]
+            })
+        );
+        assert_eq!(errors.len(), 2);
+
+        errors.clear();
+        assert_eq!(
+            parse_with_error_handler("not a comment", |err| errors.push(err)),
+            None
+        );
+        assert_eq!(errors.len(), 1);
+
+        errors.clear();
+        assert_eq!(
+            parse_with_error_handler("/** Unterminated comment", |err| errors.push(err)),
+            parse("/** Unterminated comment*/").ok()
+        );
+        assert_eq!(errors.len(), 1);
     }
 }
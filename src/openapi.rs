@@ -0,0 +1,187 @@
+//! Extraction of [OpenAPI](https://swagger.io/specification/) operation info from doc comments
+//! annotating HTTP handlers.
+
+use std::borrow::Cow;
+
+use crate::ast::{BodyItem, DocComment};
+use crate::render::{plain_text, split_first_word, split_type_expression};
+
+/// OpenAPI info of an operation documented by a doc comment.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct OpenApiInfo<'a> {
+    /// The first line of the description, rendered as plain text.
+    /// Borrowed from the input if the line is a single text segment.
+    pub summary: Cow<'a, str>,
+    /// The rest of the description, if any.
+    pub description: Option<String>,
+    /// Parameters documented with `@param` tags.
+    pub params: Vec<OpenApiParam<'a>>,
+    /// Text of the `@returns` tag, describing the response.
+    pub response: Option<String>,
+}
+
+/// A parameter documented by a `@param {Type} name description` tag.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct OpenApiParam<'a> {
+    pub name: &'a str,
+    /// Type of the parameter, without the braces.
+    pub param_type: Option<&'a str>,
+    pub description: Option<String>,
+}
+
+impl<'a> DocComment<'a> {
+    /// Extracts the OpenAPI info of the operation documented by the comment.
+    ///
+    /// The summary is the first line of the description, the rest of it becomes the description.
+    /// Parameter types are only available when the comment was parsed with
+    /// [`ParseConfig::parse_type_expressions`](crate::config::ParseConfig::parse_type_expressions)
+    /// enabled.
+    ///
+    /// Returns `None` if the comment has no description or its first line has no text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::parse_with_config;
+    /// use doctor::config::ParseConfig;
+    /// use doctor::openapi::OpenApiParam;
+    ///
+    /// let config = ParseConfig {
+    ///     parse_type_expressions: true,
+    ///     ..ParseConfig::default()
+    /// };
+    /// let doc = parse_with_config(r#"/**
+    ///     * Finds a user.
+    ///     *
+    ///     * @param {string} id the user id
+    ///     * @returns the user
+    ///     */"#, config).unwrap();
+    /// let info = doc.to_openapi_info().unwrap();
+    ///
+    /// assert_eq!(info.summary, "Finds a user.");
+    /// assert_eq!(info.description, None);
+    /// assert_eq!(
+    ///     info.params,
+    ///     vec![OpenApiParam {
+    ///         name: "id",
+    ///         param_type: Some("string"),
+    ///         description: Some("the user id".to_owned()),
+    ///     }]
+    /// );
+    /// assert_eq!(info.response.as_deref(), Some("the user"));
+    /// ```
+    #[must_use]
+    pub fn to_openapi_info(&self) -> Option<OpenApiInfo<'a>> {
+        let body_items = &self.description.as_ref()?.body_items;
+        let line_end = body_items
+            .iter()
+            .position(|item| matches!(item, BodyItem::TextSegment(s) if s.ends_with('\n')))
+            .map_or(body_items.len(), |index| index + 1);
+        let (first_line, rest) = body_items.split_at(line_end);
+        let summary = match first_line {
+            [BodyItem::TextSegment(s)] => Cow::Borrowed(s.trim()),
+            items => Cow::Owned(plain_text(items)),
+        };
+        if summary.is_empty() {
+            return None;
+        }
+
+        let params = self
+            .block_tags
+            .iter()
+            .filter(|tag| tag.name == "param")
+            .map(|tag| {
+                let (param_type, rest) = split_type_expression(&tag.body_items);
+                let (name, description) = split_first_word(rest);
+                OpenApiParam {
+                    name,
+                    param_type,
+                    description: non_empty(description),
+                }
+            })
+            .collect();
+        let response = self
+            .block_tags
+            .iter()
+            .find(|tag| matches!(tag.name, "returns" | "return"))
+            .map(|tag| plain_text(&tag.body_items));
+
+        Some(OpenApiInfo {
+            summary,
+            description: non_empty(plain_text(rest)),
+            params,
+            response,
+        })
+    }
+}
+
+fn non_empty(s: String) -> Option<String> {
+    Some(s).filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ParseConfig;
+    use crate::{parse, parse_with_config};
+
+    #[test]
+    fn test_to_openapi_info() {
+        let config = ParseConfig {
+            parse_type_expressions: true,
+            ..ParseConfig::default()
+        };
+        let doc = parse_with_config(
+            r"/**
+            * Lists the orders.
+            * Supports {@link Pagination}.
+            *
+            * @param {number} limit
+            * @param offset the offset
+            * @return the orders
+            */",
+            config,
+        )
+        .unwrap();
+
+        assert_eq!(
+            doc.to_openapi_info(),
+            Some(OpenApiInfo {
+                summary: Cow::Borrowed("Lists the orders."),
+                description: Some("Supports Pagination.".to_owned()),
+                params: vec![
+                    OpenApiParam {
+                        name: "limit",
+                        param_type: Some("number"),
+                        description: None,
+                    },
+                    OpenApiParam {
+                        name: "offset",
+                        param_type: None,
+                        description: Some("the offset".to_owned()),
+                    },
+                ],
+                response: Some("the orders".to_owned()),
+            })
+        );
+        assert_eq!(
+            parse("/** @returns nothing */").unwrap().to_openapi_info(),
+            None
+        );
+        assert_eq!(parse("/** */").unwrap().to_openapi_info(), None);
+
+        let info = parse("/**\n * Finds a {@link User} by id.\n * More.\n */")
+            .unwrap()
+            .to_openapi_info()
+            .unwrap();
+        assert_eq!(info.summary, "Finds a User by id.");
+        assert_eq!(info.description.as_deref(), Some("More."));
+
+        let info = parse("/** {@link Foo} */")
+            .unwrap()
+            .to_openapi_info()
+            .unwrap();
+        assert_eq!(info.summary, "Foo");
+        assert_eq!(info.description, None);
+    }
+}
@@ -1,19 +1,20 @@
 use nom::branch::alt;
-use nom::bytes::complete::{escaped, is_not, tag};
+use nom::bytes::complete::{escaped, is_not, tag, take_till1, take_until};
 use nom::character::complete::{
-    alphanumeric1, char, line_ending, multispace0, one_of, space0, space1,
+    alphanumeric1, char, line_ending, multispace0, not_line_ending, one_of, space0, space1,
 };
 use nom::character::streaming::alpha1;
 use nom::combinator::{all_consuming, not, opt, recognize, verify};
-use nom::error::{context, make_error, ErrorKind, VerboseError};
-use nom::multi::{fold_many1, many0, separated_list1};
-use nom::sequence::{delimited, pair, preceded, tuple};
-use nom::{IResult, Parser};
+use nom::error::{context, make_error, ContextError, ErrorKind, VerboseError};
+use nom::multi::{fold_many1, many0, many1, separated_list1};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+use nom::{IResult, InputLength, Parser};
 
 use crate::ast::{BlockTag, BodyItem, Description, DocComment, InlineTag};
+use crate::config::{ParseConfig, TagNameSyntax};
 
 /// Eats the doc comment start sequence.
-fn comment_start(i: &str) -> IResult<&str, (), VerboseError<&str>> {
+pub fn comment_start(i: &str) -> IResult<&str, (), VerboseError<&str>> {
     context(
         "comment_start",
         tuple((tag("/**"), space0, opt(line_ending))),
@@ -22,15 +23,33 @@ fn comment_start(i: &str) -> IResult<&str, (), VerboseError<&str>> {
     .parse(i)
 }
 
+/// Eats the doc comment start sequence, also accepting `/*!` if `config` allows it.
+fn configured_comment_start<'a>(
+    config: ParseConfig,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (), VerboseError<&'a str>> {
+    move |i: &'a str| {
+        if config.allow_bang_comment_start {
+            context(
+                "comment_start",
+                tuple((alt((tag("/**"), tag("/*!"))), space0, opt(line_ending))),
+            )
+            .map(|_| ())
+            .parse(i)
+        } else {
+            comment_start(i)
+        }
+    }
+}
+
 /// Eats the doc comment end sequence.
-fn comment_end(i: &str) -> IResult<&str, (), VerboseError<&str>> {
+pub fn comment_end(i: &str) -> IResult<&str, (), VerboseError<&str>> {
     context("comment_end", tuple((multispace0, tag("*/"))))
         .map(|_| ())
         .parse(i)
 }
 
 /// Parses a single comment line leading, i.e. ` * `.
-fn line_leading(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+pub fn line_leading(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
     context(
         "line_leading",
         recognize(tuple((space0, not(tag("*/")), tag("*"), space0))),
@@ -39,24 +58,69 @@ fn line_leading(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
 }
 
 /// Parses an inline or block tag name.
-fn tag_name(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
-    context(
-        "tag_name",
-        preceded(
-            tag("@"),
-            recognize(pair(alpha1, many0(alt((alphanumeric1, tag("_")))))),
-        ),
-    )
+pub fn tag_name(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+    context("tag_name", preceded(tag("@"), standard_name)).parse(i)
+}
+
+/// Parses a tag name that may also start with `_` and contain `-`, as allowed by TSDoc.
+fn extended_tag_name(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+    context("tag_name", preceded(tag("@"), extended_name)).parse(i)
+}
+
+/// Parses a tag name consisting of ASCII letters only, as required by classic JavaDoc.
+fn alphabetic_tag_name(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+    context("tag_name", preceded(tag("@"), alphabetic_name)).parse(i)
+}
+
+/// Parses the name of a [`tag_name`] without the leading `@`.
+fn standard_name(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+    recognize(pair(alpha1, many0(alt((alphanumeric1, tag("_")))))).parse(i)
+}
+
+/// Parses the name of an [`extended_tag_name`] without the leading `@`.
+fn extended_name(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+    recognize(pair(
+        alt((alpha1, tag("_"))),
+        many0(alt((alphanumeric1, tag("_"), tag("-")))),
+    ))
     .parse(i)
 }
 
+/// Parses the name of an [`alphabetic_tag_name`] without the leading `@`.
+fn alphabetic_name(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+    nom::character::complete::alpha1(i)
+}
+
+/// Parses a tag name following the syntax selected by `config`.
+/// If `config` allows backslash tags, the name may be prefixed with `\` instead of `@`.
+fn configured_tag_name<'a>(
+    config: ParseConfig,
+) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str, VerboseError<&'a str>> {
+    move |i: &'a str| {
+        if config.allow_backslash_tags {
+            let name = |i: &'a str| match config.tag_name_syntax {
+                TagNameSyntax::Standard => standard_name(i),
+                TagNameSyntax::Extended => extended_name(i),
+                TagNameSyntax::Alphabetic => alphabetic_name(i),
+            };
+            return context("tag_name", preceded(alt((tag("@"), tag("\\"))), name)).parse(i);
+        }
+
+        match config.tag_name_syntax {
+            TagNameSyntax::Standard => tag_name(i),
+            TagNameSyntax::Extended => extended_tag_name(i),
+            TagNameSyntax::Alphabetic => alphabetic_tag_name(i),
+        }
+    }
+}
+
 /// Returns an error if the parsed output of the provided parser is empty.
-fn non_empty<'a>(
-    mut parser: impl Parser<&'a str, &'a str, VerboseError<&'a str>>,
-) -> impl Parser<&'a str, &'a str, VerboseError<&'a str>> {
+pub fn non_empty<'a, O: InputLength>(
+    mut parser: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl Parser<&'a str, O, VerboseError<&'a str>> {
     move |i: &'a str| {
         let result = parser.parse(i)?;
-        if result.1.is_empty() {
+        if result.1.input_len() == 0 {
             Err(nom::Err::Error(make_error(i, ErrorKind::NonEmpty)))
         } else {
             Ok(result)
@@ -65,181 +129,726 @@ fn non_empty<'a>(
 }
 
 /// Parses a single line of an inline tag's body.
-fn inline_tag_body_line(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+/// Braces have to be escaped as configured by `config`.
+fn inline_tag_body_line<'a>(
+    config: ParseConfig,
+) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str, VerboseError<&'a str>> {
+    move |i: &'a str| {
+        context(
+            "inline_tag_body_line",
+            alt((
+                line_ending,
+                recognize(tuple((
+                    |i| inline_tag_body_text(config, 1, i),
+                    opt(line_ending),
+                ))),
+            )),
+        )
+        .parse(i)
+    }
+}
+
+/// Parses the text of a body line of an inline tag nested `depth` levels deep.
+/// Inline tags nested in the text are recognized as part of it until
+/// [`ParseConfig::max_inline_tag_depth`] is reached, deeper ones are kept as unparsed text.
+fn inline_tag_body_text(
+    config: ParseConfig,
+    depth: usize,
+    i: &str,
+) -> IResult<&str, &str, VerboseError<&str>> {
+    let text = |i| match Escape::from_config(config) {
+        Escape::Char(escape_char) => escaped(
+            take_till1(|ch| ch == escape_char || "\r\n{}".contains(ch)),
+            escape_char,
+            one_of("{}"),
+        )
+        .parse(i),
+        Escape::Doubling => {
+            recognize(many1(alt((is_not("\r\n{}"), tag("{{"), tag("}}"))))).parse(i)
+        }
+    };
+    if depth < config.max_inline_tag_depth {
+        recognize(many1(alt((non_empty(text), |i| {
+            nested_inline_tag(config, depth + 1, i)
+        }))))
+        .parse(i)
+    } else {
+        // Reports the error of the text parser, unlike `many1` and `alt`,
+        // as nested tags are only expected when nesting is enabled.
+        let item = |i| {
+            non_empty(text)
+                .parse(i)
+                .or_else(|err| over_deep_inline_tag(config, i).map_err(|_| err))
+        };
+        let (mut rest, _) = item(i)?;
+        while let Ok((next, _)) = item(rest) {
+            rest = next;
+        }
+        Ok((rest, &i[..i.len() - rest.len()]))
+    }
+}
+
+/// Recognizes a single-line inline tag nested `depth` levels deep in the body of another one.
+fn nested_inline_tag(
+    config: ParseConfig,
+    depth: usize,
+    i: &str,
+) -> IResult<&str, &str, VerboseError<&str>> {
     context(
-        "inline_tag_body_line",
-        alt((
-            line_ending,
-            recognize(tuple((
-                non_empty(escaped(is_not("\\\r\n{}"), '\\', one_of("{}"))),
-                opt(line_ending),
-            ))),
-        )),
+        "nested_inline_tag",
+        recognize(tuple((
+            char('{'),
+            configured_tag_name(config),
+            opt(preceded(space1, |i| inline_tag_body_text(config, depth, i))),
+            space0,
+            char('}'),
+        ))),
     )
     .parse(i)
 }
 
+/// Recognizes a single-line inline tag nested deeper than [`ParseConfig::max_inline_tag_depth`]
+/// as plain text, matching its braces without recursing into the tags nested in it.
+fn over_deep_inline_tag(config: ParseConfig, i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+    preceded(char('{'), configured_tag_name(config)).parse(i)?;
+
+    let escape = Escape::from_config(config);
+    let mut open_braces = 0;
+    let mut chars = i.char_indices().peekable();
+    while let Some((index, ch)) = chars.next() {
+        match (ch, escape) {
+            ('\r' | '\n', _) => break,
+            (ch, Escape::Char(escape_char)) if ch == escape_char => {
+                chars.next();
+            }
+            ('{' | '}', Escape::Doubling) if chars.peek().is_some_and(|&(_, next)| next == ch) => {
+                chars.next();
+            }
+            ('{', _) => open_braces += 1,
+            ('}', _) => {
+                open_braces -= 1;
+                if open_braces == 0 {
+                    let (tag, rest) = i.split_at(index + 1);
+                    return Ok((rest, tag));
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(nom::Err::Error(VerboseError::add_context(
+        i,
+        "over_deep_inline_tag",
+        make_error(i, ErrorKind::Char),
+    )))
+}
+
+/// Parses a comment line leading.
+/// If `config` doesn't require line leadings, a bare indentation is accepted instead.
+fn line_leading_or_indent<'a>(
+    config: ParseConfig,
+) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str, VerboseError<&'a str>> {
+    move |i: &'a str| {
+        if config.require_line_leading {
+            line_leading(i)
+        } else {
+            alt((line_leading, space1)).parse(i)
+        }
+    }
+}
+
 /// Parses an inline tag's body.
 /// It might contain multiple lines of text.
-fn inline_tag_body(i: &str) -> IResult<&str, Vec<&str>, VerboseError<&str>> {
+fn inline_tag_body<'a>(
+    config: ParseConfig,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<&'a str>, VerboseError<&'a str>> {
+    move |i: &'a str| {
+        if config.require_line_leading {
+            context(
+                "inline_tag_body",
+                separated_list1(line_leading, inline_tag_body_line(config)),
+            )
+            .parse(i)
+        } else {
+            context(
+                "inline_tag_body",
+                many1(preceded(
+                    opt(line_leading_or_indent(config)),
+                    inline_tag_body_line(config),
+                )),
+            )
+            .parse(i)
+        }
+    }
+}
+
+fn inline_tag<'a>(
+    config: ParseConfig,
+) -> impl FnMut(&'a str) -> IResult<&'a str, InlineTag<'a>, VerboseError<&'a str>> {
+    move |i: &'a str| {
+        context(
+            "inline_tag",
+            delimited(
+                char('{'),
+                tuple((
+                    configured_tag_name(config),
+                    opt(preceded(opt(space1), inline_tag_body(config))),
+                )),
+                preceded(opt(line_leading_or_indent(config)), char('}')),
+            ),
+        )
+        .map(|(name, maybe_body_lines)| InlineTag {
+            name,
+            body_lines: maybe_body_lines.unwrap_or_else(Vec::new),
+        })
+        .parse(i)
+    }
+}
+
+/// Converts an inline tag into the body item representing it according to `config`.
+fn inline_tag_to_body_item(config: ParseConfig, tag: InlineTag<'_>) -> BodyItem<'_> {
+    match (tag.name, tag.body_lines.as_slice()) {
+        ("code", []) if config.parse_code_spans => BodyItem::CodeSpan(""),
+        ("code", [code]) if config.parse_code_spans => BodyItem::CodeSpan(code),
+        ("inheritDoc", []) if config.parse_inherit_doc => BodyItem::InheritDoc,
+        ("link", [body]) if config.parse_links && !body.trim().is_empty() => {
+            // TSDoc separates the label with `|`, JavaDoc and JSDoc with whitespace.
+            let body = body.trim();
+            let (target, label) = body
+                .split_once('|')
+                .or_else(|| body.split_once(char::is_whitespace))
+                .map_or((body, None), |(target, label)| {
+                    (target.trim_end(), Some(label.trim()))
+                });
+            BodyItem::Link { target, label }
+        }
+        _ => BodyItem::InlineTag(tag),
+    }
+}
+
+/// Parses the line leading of a code block line.
+/// Unlike [`line_leading`], only a single space after `*` is consumed to preserve the indentation.
+fn code_line_leading(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
     context(
-        "inline_tag_body",
-        separated_list1(line_leading, inline_tag_body_line),
+        "code_line_leading",
+        recognize(tuple((space0, not(tag("*/")), char('*'), opt(char(' '))))),
     )
     .parse(i)
 }
 
-fn inline_tag(i: &str) -> IResult<&str, InlineTag<'_>, VerboseError<&str>> {
+/// Parses a code block fence, i.e. three or more backticks.
+fn code_fence(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+    context("code_fence", recognize(pair(tag("```"), many0(char('`'))))).parse(i)
+}
+
+/// Parses a single line of a code block, including its line ending.
+fn code_line(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
     context(
-        "inline_tag",
-        delimited(
-            char('{'),
-            tuple((tag_name, opt(preceded(opt(space1), inline_tag_body)))),
-            preceded(opt(line_leading), char('}')),
+        "code_line",
+        preceded(
+            not(pair(space0, alt((code_fence, tag("*/"))))),
+            recognize(pair(not_line_ending, line_ending)),
         ),
     )
-    .map(|(name, maybe_body_lines)| InlineTag {
-        name,
-        body_lines: maybe_body_lines.unwrap_or_else(Vec::new),
-    })
     .parse(i)
 }
 
-/// Parses an single text segment of a description's or block tag's body.
-/// A segment is usually terminated by either an inline tag or a line ending.
-fn body_text_segment(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+/// Parses a fenced code block, from the opening fence up to and including the closing fence line.
+fn code_block(i: &str) -> IResult<&str, BodyItem<'_>, VerboseError<&str>> {
     context(
-        "body_text_segment",
-        alt((
-            line_ending,
-            recognize(tuple((
-                verify(
-                    take_until_either(&[
-                        Token::Escapable("{"),
-                        Token::Escapable("}"),
-                        Token::Escapable("@"),
-                        Token::NonEscapable("\r"),
-                        Token::NonEscapable("\n"),
-                        Token::NonEscapable("*/"),
-                    ]),
-                    // The segment has to be non-empty and not whitespace-only.
-                    |s: &str| {
-                        !s.is_empty() && s.chars().any(|ch| !ch.is_whitespace() && ch != '\t')
-                    },
-                ),
-                opt(line_ending),
-            ))),
+        "code_block",
+        tuple((
+            terminated(
+                preceded(code_fence, opt(is_not(" \t\r\n`"))),
+                pair(space0, line_ending),
+            ),
+            many0(preceded(opt(code_line_leading), code_line)),
+            preceded(
+                tuple((opt(code_line_leading), space0, code_fence)),
+                pair(space0, opt(line_ending)),
+            ),
         )),
     )
+    .map(|(language, lines, _)| BodyItem::CodeBlock { language, lines })
     .parse(i)
 }
 
+/// Returns the tokens terminating a body text segment according to `config`.
+/// They are returned in an array along with their count, so that no allocation is needed.
+fn body_text_segment_tokens(config: ParseConfig) -> ([Token<'static>; 8], usize) {
+    let mut tokens = [Token::NonEscapable("*/"); 8];
+    let mut len = 0;
+    let configured_tokens = [
+        Some(Token::Escapable(if config.strict_inline_brace {
+            "{@"
+        } else {
+            "{"
+        })),
+        (!config.strict_inline_brace).then_some(Token::Escapable("}")),
+        Some(Token::Escapable("@")),
+        config
+            .allow_backslash_tags
+            .then_some(Token::TagPrefix("\\")),
+        config.parse_kdoc_links.then_some(Token::Escapable("[")),
+        Some(Token::NonEscapable("\r")),
+        Some(Token::NonEscapable("\n")),
+        Some(Token::NonEscapable("*/")),
+    ];
+    for token in configured_tokens.iter().flatten() {
+        tokens[len] = *token;
+        len += 1;
+    }
+    (tokens, len)
+}
+
+/// Parses a KDoc `[Foo]` reference link.
+fn kdoc_link(i: &str) -> IResult<&str, BodyItem<'_>, VerboseError<&str>> {
+    context(
+        "kdoc_link",
+        delimited(
+            char('['),
+            verify(is_not("[]\r\n"), |target: &str| !target.trim().is_empty()),
+            char(']'),
+        ),
+    )
+    .map(|target: &str| BodyItem::Link {
+        target: target.trim(),
+        label: None,
+    })
+    .parse(i)
+}
+
+/// Parses an single text segment of a description's or block tag's body.
+/// A segment is usually terminated by either an inline tag or a line ending.
+///
+/// `{@` (or `{` and `}` if [`ParseConfig::strict_inline_brace`] is disabled), `@`
+/// and `[` if [`ParseConfig::parse_kdoc_links`] is enabled
+/// have to be escaped as configured by `config` to be part of the segment.
+/// If [`ParseConfig::allow_backslash_tags`] is enabled, `\name` also terminates the segment.
+///
+/// `*/` always terminates the segment, even when preceded by a backslash:
+/// the comment ends there in the host language anyway, so `\*/` can't be used to escape it.
+fn body_text_segment<'a>(
+    config: ParseConfig,
+) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str, VerboseError<&'a str>> {
+    move |i: &'a str| {
+        let (tokens, len) = body_text_segment_tokens(config);
+        let mut parser = context(
+            "body_text_segment",
+            alt((
+                line_ending,
+                recognize(tuple((
+                    verify(
+                        take_until_either(&tokens[..len], Escape::from_config(config)),
+                        // The segment has to be non-empty and not whitespace-only.
+                        |s: &str| {
+                            !s.is_empty() && s.chars().any(|ch| !ch.is_whitespace() && ch != '\t')
+                        },
+                    ),
+                    opt(line_ending),
+                ))),
+            )),
+        );
+        parser.parse(i)
+    }
+}
+
 /// Parses body of a description or a block tag.
-fn body(i: &str) -> IResult<&str, Vec<BodyItem<'_>>, VerboseError<&str>> {
+pub fn body<'a>(
+    config: ParseConfig,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<BodyItem<'a>>, VerboseError<&'a str>> {
     #[derive(Debug)]
     enum ParsedEntities<'a> {
         BodyItem(BodyItem<'a>),
         Ignored,
     }
 
-    verify(
-        fold_many1(
-            alt((
-                line_leading.map(|_| ParsedEntities::Ignored),
-                space1.map(|_| ParsedEntities::Ignored),
-                inline_tag
-                    .map(BodyItem::InlineTag)
-                    .map(ParsedEntities::BodyItem),
-                body_text_segment
-                    .map(BodyItem::TextSegment)
-                    .map(ParsedEntities::BodyItem),
-            )),
-            vec![],
-            |mut items, item| {
-                if let ParsedEntities::BodyItem(item) = item {
-                    items.push(item)
-                }
-                items
+    move |i: &'a str| {
+        verify(
+            fold_many1(
+                alt((
+                    line_leading.map(|_| ParsedEntities::Ignored),
+                    space1.map(|_| ParsedEntities::Ignored),
+                    inline_tag(config)
+                        .map(|tag| inline_tag_to_body_item(config, tag))
+                        .map(ParsedEntities::BodyItem),
+                    |i| {
+                        if config.parse_code_blocks {
+                            code_block.map(ParsedEntities::BodyItem).parse(i)
+                        } else {
+                            Err(nom::Err::Error(make_error(i, ErrorKind::Alt)))
+                        }
+                    },
+                    |i| {
+                        if config.parse_kdoc_links {
+                            kdoc_link.map(ParsedEntities::BodyItem).parse(i)
+                        } else {
+                            Err(nom::Err::Error(make_error(i, ErrorKind::Alt)))
+                        }
+                    },
+                    body_text_segment(config)
+                        .map(BodyItem::TextSegment)
+                        .map(ParsedEntities::BodyItem),
+                    // A `[` not starting a KDoc link is plain text.
+                    |i| {
+                        if config.parse_kdoc_links {
+                            tag("[")
+                                .map(BodyItem::TextSegment)
+                                .map(ParsedEntities::BodyItem)
+                                .parse(i)
+                        } else {
+                            Err(nom::Err::Error(make_error(i, ErrorKind::Alt)))
+                        }
+                    },
+                )),
+                vec![],
+                |mut items, item| {
+                    if let ParsedEntities::BodyItem(item) = item {
+                        items.push(item);
+                    }
+                    items
+                },
+            ),
+            |body_items: &Vec<BodyItem>| {
+                // Don't consider empty or whitespace-only lines a body.
+                body_items.iter().any(|item| match item {
+                    BodyItem::TextSegment(s) => !is_empty_or_multispace(s),
+                    BodyItem::InlineTag(_)
+                    | BodyItem::CodeSpan(_)
+                    | BodyItem::CodeBlock { .. }
+                    | BodyItem::Link { .. }
+                    | BodyItem::InheritDoc => true,
+                })
             },
-        ),
-        |body_items: &Vec<BodyItem>| {
-            // Don't consider empty or whitespace-only lines a body.
-            body_items.iter().any(|item| match item {
-                BodyItem::TextSegment(s) => !is_empty_or_multispace(s),
-                BodyItem::InlineTag(_) => true,
-            })
-        },
+        )
+        .parse(i)
+    }
+}
+
+/// Parses a description section of a doc comment.
+fn description<'a>(
+    config: ParseConfig,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Description<'a>, VerboseError<&'a str>> {
+    move |i: &'a str| {
+        context("description", body(config))
+            .map(|body_items| Description { body_items })
+            .parse(i)
+    }
+}
+
+/// Parses a `{Type}` expression at the start of a block tag body.
+fn type_expression(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+    context(
+        "type_expression",
+        recognize(delimited(char('{'), is_not("{}@\r\n"), char('}'))),
     )
     .parse(i)
 }
 
-/// Parses a description section of a doc comment.
-fn description(i: &str) -> IResult<&str, Description<'_>, VerboseError<&str>> {
-    context("description", body)
-        .map(|body_items| Description { body_items })
-        .parse(i)
+/// Parses an `@example` tag title in the `<caption>title</caption>` form.
+fn example_caption(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+    context(
+        "example_caption",
+        recognize(delimited(
+            tag("<caption>"),
+            verify(take_until("</caption>"), |title: &str| {
+                !title.contains(['\r', '\n'])
+            }),
+            tag("</caption>"),
+        )),
+    )
+    .parse(i)
 }
 
 /// Parses a single block tag.
-fn block_tag(i: &str) -> IResult<&str, BlockTag<'_>, VerboseError<&str>> {
-    context("block_tag", tuple((tag_name, space0, opt(body))))
-        .map(|(name, _, maybe_body_items)| BlockTag {
-            name,
-            body_items: maybe_body_items.unwrap_or_else(Vec::new),
+fn block_tag<'a>(
+    config: ParseConfig,
+) -> impl FnMut(&'a str) -> IResult<&'a str, BlockTag<'a>, VerboseError<&'a str>> {
+    move |i: &'a str| {
+        let (i, (name, _)) =
+            context("block_tag", tuple((configured_tag_name(config), space0))).parse(i)?;
+        let (i, maybe_type) =
+            if config.parse_type_expressions || (config.parse_prop_tags && name == "prop") {
+                opt(terminated(type_expression, space0)).parse(i)?
+            } else if config.parse_example_titles && name == "example" {
+                opt(terminated(example_caption, space0)).parse(i)?
+            } else {
+                (i, None)
+            };
+        context("block_tag", opt(body(config)))
+            .map(|maybe_body_items| BlockTag {
+                name,
+                position: 0,
+                body_items: maybe_type
+                    .map(BodyItem::TextSegment)
+                    .into_iter()
+                    .chain(maybe_body_items.into_iter().flatten())
+                    .collect(),
+            })
+            .parse(i)
+    }
+}
+
+/// Parses the content of a doc comment between its start and end sequences.
+fn doc_comment_content<'a>(
+    config: ParseConfig,
+) -> impl FnMut(&'a str) -> IResult<&'a str, DocComment<'a>, VerboseError<&'a str>> {
+    move |i: &'a str| {
+        tuple((
+            opt(line_leading_or_indent(config)),
+            opt(description(config)),
+            many0(delimited(
+                opt(line_leading_or_indent(config)),
+                block_tag(config),
+                opt(line_ending),
+            )),
+        ))
+        .map(|(_, description, mut block_tags)| {
+            for (position, block_tag) in block_tags.iter_mut().enumerate() {
+                block_tag.position = position;
+            }
+            DocComment {
+                description,
+                block_tags,
+            }
         })
         .parse(i)
+    }
 }
 
 /// Parses an entire doc comment.
-pub fn doc_comment(i: &str) -> IResult<&str, DocComment<'_>, VerboseError<&str>> {
+pub fn doc_comment<'a>(
+    config: ParseConfig,
+) -> impl FnMut(&'a str) -> IResult<&'a str, DocComment<'a>, VerboseError<&'a str>> {
+    move |i: &'a str| {
+        context(
+            "doc_comment",
+            all_consuming(tuple((
+                configured_comment_start(config),
+                doc_comment_content(config),
+                comment_end,
+            ))),
+        )
+        .map(|((), doc, ())| doc)
+        .parse(i)
+    }
+}
+
+/// Parses an entire doc comment delimited by `start` and `end` instead of `/**` and `*/`.
+/// The input has to end with `end`, so that it doesn't need to be recognized inside the comment.
+pub fn delimited_doc_comment<'a>(
+    config: ParseConfig,
+    start: &str,
+    end: &str,
+    i: &'a str,
+) -> IResult<&'a str, DocComment<'a>, VerboseError<&'a str>> {
+    let (content, _) = context(
+        "comment_start",
+        tuple((tag(start), space0, opt(line_ending))),
+    )
+    .parse(i)?;
+    let eof = &i[i.len()..];
+    let content = content.strip_suffix(end).ok_or_else(|| {
+        nom::Err::Error(VerboseError::add_context(
+            eof,
+            "comment_end",
+            make_error(eof, ErrorKind::Tag),
+        ))
+    })?;
+
     context(
         "doc_comment",
-        all_consuming(tuple((
-            comment_start,
-            opt(line_leading),
-            opt(description),
-            many0(delimited(opt(line_leading), block_tag, opt(line_ending))),
-            comment_end,
-        ))),
+        all_consuming(terminated(doc_comment_content(config), multispace0)),
     )
-    .map(|(_, _, description, block_tags, _)| DocComment {
-        description,
+    .map(|doc| (eof, doc))
+    .parse(content)
+    .map(|(_, result)| result)
+}
+
+/// Parses an entire doc comment, recovering from errors by skipping the rest of the offending line.
+/// Every encountered error is passed to `on_error`.
+/// Returns `None` if the input doesn't even start like a doc comment.
+pub fn doc_comment_with_recovery<'a>(
+    config: ParseConfig,
+    i: &'a str,
+    mut on_error: impl FnMut(VerboseError<&'a str>),
+) -> Option<DocComment<'a>> {
+    let mut i = match configured_comment_start(config)(i) {
+        Ok((rest, ())) => rest,
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            on_error(err);
+            return None;
+        }
+        Err(nom::Err::Incomplete(_)) => return None,
+    };
+    let mut description_items = vec![];
+    let mut block_tags: Vec<BlockTag> = vec![];
+
+    loop {
+        if let Ok((rest, ())) = comment_end(i) {
+            if !rest.is_empty() {
+                on_error(make_error(rest, ErrorKind::Eof));
+            }
+            break;
+        }
+        if i.is_empty() {
+            if let Err(nom::Err::Error(err) | nom::Err::Failure(err)) = comment_end(i) {
+                on_error(err);
+            }
+            break;
+        }
+
+        if block_tags.is_empty() {
+            if let Ok((rest, description)) =
+                preceded(opt(line_leading_or_indent(config)), description(config)).parse(i)
+            {
+                description_items.extend(description.body_items);
+                i = rest;
+                continue;
+            }
+        }
+
+        // Skip blank lines.
+        if let Ok((rest, _)) =
+            tuple((opt(line_leading_or_indent(config)), space0, line_ending)).parse(i)
+        {
+            i = rest;
+            continue;
+        }
+
+        match delimited(
+            opt(line_leading_or_indent(config)),
+            block_tag(config),
+            opt(line_ending),
+        )
+        .parse(i)
+        {
+            Ok((rest, mut block_tag)) => {
+                block_tag.position = block_tags.len();
+                block_tags.push(block_tag);
+                i = rest;
+            }
+            Err(err) => {
+                if let nom::Err::Error(err) | nom::Err::Failure(err) = err {
+                    on_error(err);
+                }
+                // Skip the rest of the line.
+                i = i
+                    .find('\n')
+                    .map_or_else(|| &i[i.len()..], |index| &i[index + 1..]);
+            }
+        }
+    }
+
+    Some(DocComment {
+        description: if description_items.is_empty() {
+            None
+        } else {
+            Some(Description {
+                body_items: description_items,
+            })
+        },
         block_tags,
     })
-    .parse(i)
 }
 
-#[derive(Debug)]
-enum Token<'a> {
+/// Token stopping a [`TakeUntilEither`] parser.
+#[derive(Debug, Clone, Copy)]
+pub enum Token<'a> {
+    /// A token that doesn't stop the parser when escaped.
     Escapable(&'a str),
+    /// A token that stops the parser even when escaped.
     NonEscapable(&'a str),
+    /// A tag name prefix other than `@`, e.g. `\`, which only counts when followed by a letter.
+    /// It is recognized even if it's the escape character.
+    TagPrefix(&'a str),
+}
+
+/// How [`Token::Escapable`] tokens are escaped.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Escape {
+    /// The token is preceded by the escape character.
+    Char(char),
+    /// The first character of the token is repeated twice.
+    Doubling,
+}
+
+impl Escape {
+    /// Returns the escaping configured by `config`.
+    const fn from_config(config: ParseConfig) -> Self {
+        if config.use_doubling_escape {
+            Self::Doubling
+        } else {
+            Self::Char(config.escape_char)
+        }
+    }
+}
+
+/// Parser returned by [`take_until_either`].
+/// Unlike an opaque `impl Parser`, it can be named and stored in struct fields.
+#[derive(Debug, Clone, Copy)]
+pub struct TakeUntilEither<'t>(&'t [Token<'t>], Escape);
+
+impl TakeUntilEither<'_> {
+    /// Returns `true` if an escapable token starts with `ch`.
+    fn is_escapable_start(&self, ch: char) -> bool {
+        self.0
+            .iter()
+            .any(|token| matches!(token, Token::Escapable(t) if t.starts_with(ch)))
+    }
 }
 
-fn take_until_either<'a>(
-    tokens: &'a [Token<'a>],
-) -> impl Parser<&'a str, &'a str, VerboseError<&'a str>> {
-    move |input: &'a str| {
+impl<'a> Parser<&'a str, &'a str, VerboseError<&'a str>> for TakeUntilEither<'_> {
+    fn parse(&mut self, input: &'a str) -> IResult<&'a str, &'a str, VerboseError<&'a str>> {
         let mut escaping = false;
-        let chars = input.char_indices();
-        for (i, ch) in chars {
-            let next_escaping = ch == '\\' && !escaping;
-            if next_escaping {
-                escaping = next_escaping;
+        // End of the last doubled character, which is skipped as a whole.
+        let mut skip_until = 0;
+        for (i, ch) in input.char_indices() {
+            if i < skip_until {
                 continue;
             }
+            let is_tag_start = |t: &str| {
+                input[i..]
+                    .strip_prefix(t)
+                    .is_some_and(|rest| rest.starts_with(|ch: char| ch.is_ascii_alphabetic()))
+            };
+            if !escaping
+                && self
+                    .0
+                    .iter()
+                    .any(|token| matches!(token, Token::TagPrefix(t) if is_tag_start(t)))
+            {
+                let (parsed, rest) = input.split_at(i);
+                return Ok((rest, parsed));
+            }
+
+            match self.1 {
+                Escape::Char(escape_char) if ch == escape_char && !escaping => {
+                    escaping = true;
+                    continue;
+                }
+                Escape::Doubling
+                    if self.is_escapable_start(ch)
+                        && input[i + ch.len_utf8()..].starts_with(ch) =>
+                {
+                    skip_until = i + 2 * ch.len_utf8();
+                    continue;
+                }
+                _ => {}
+            }
 
-            for token in tokens {
+            for token in self.0 {
                 let found = match token {
                     Token::Escapable(t) => !escaping && input[i..].starts_with(t),
                     Token::NonEscapable(t) => input[i..].starts_with(t),
+                    Token::TagPrefix(_) => false,
                 };
                 if found {
                     let (parsed, rest) = input.split_at(i);
                     return Ok((rest, parsed));
-                };
+                }
             }
 
-            escaping = next_escaping;
+            escaping = false;
         }
 
         // Returning an empty &str as the "rest" causes a runtime panic in code that works with this "rest".
@@ -249,7 +858,14 @@ fn take_until_either<'a>(
     }
 }
 
-fn is_empty_or_multispace(s: &str) -> bool {
+/// Consumes the input until one of the `tokens` is found.
+/// Escapable tokens escaped according to `escape` don't stop the parser.
+#[must_use]
+pub const fn take_until_either<'t>(tokens: &'t [Token<'t>], escape: Escape) -> TakeUntilEither<'t> {
+    TakeUntilEither(tokens, escape)
+}
+
+pub fn is_empty_or_multispace(s: &str) -> bool {
     if s.is_empty() {
         return true;
     }
@@ -391,24 +1007,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extended_tag_name() {
+        assert_eq!(extended_tag_name("@my-tag"), Ok(("", "my-tag")));
+        assert_eq!(extended_tag_name("@_myTag rest"), Ok((" rest", "_myTag")));
+        assert_eq!(extended_tag_name("@myTag1"), Ok(("", "myTag1")));
+        assert!(extended_tag_name("@-myTag").is_err());
+        assert!(extended_tag_name("@1myTag").is_err());
+    }
+
+    #[test]
+    fn test_alphabetic_tag_name() {
+        assert_eq!(alphabetic_tag_name("@myTag"), Ok(("", "myTag")));
+        assert_eq!(alphabetic_tag_name("@my_tag1"), Ok(("_tag1", "my")));
+        assert!(alphabetic_tag_name("@_myTag").is_err());
+    }
+
+    #[test]
+    fn test_inline_tag_to_body_item_links() {
+        let config = ParseConfig {
+            parse_links: true,
+            ..ParseConfig::default()
+        };
+
+        assert_eq!(
+            inline_tag_to_body_item(config, InlineTag::new("link", " Foo ")),
+            BodyItem::Link {
+                target: "Foo",
+                label: None
+            }
+        );
+        assert_eq!(
+            inline_tag_to_body_item(config, InlineTag::new("link", "Foo#bar the  bar")),
+            BodyItem::Link {
+                target: "Foo#bar",
+                label: Some("the  bar")
+            }
+        );
+        assert_eq!(
+            inline_tag_to_body_item(config, InlineTag::new("link", "Foo|bar")),
+            BodyItem::Link {
+                target: "Foo",
+                label: Some("bar")
+            }
+        );
+        assert_eq!(
+            inline_tag_to_body_item(config, InlineTag::new("link", "")),
+            BodyItem::InlineTag(InlineTag::new("link", ""))
+        );
+        assert_eq!(
+            inline_tag_to_body_item(ParseConfig::default(), InlineTag::new("link", "Foo")),
+            BodyItem::InlineTag(InlineTag::new("link", "Foo"))
+        );
+    }
+
+    #[test]
+    fn test_inline_tag_to_body_item_inherit_doc() {
+        let config = ParseConfig {
+            parse_inherit_doc: true,
+            ..ParseConfig::default()
+        };
+
+        assert_eq!(
+            inline_tag(config)("{@inheritDoc}")
+                .map(|(i, tag)| (i, inline_tag_to_body_item(config, tag))),
+            Ok(("", BodyItem::InheritDoc))
+        );
+        assert_eq!(
+            inline_tag_to_body_item(config, InlineTag::new("inheritDoc", "Foo")),
+            BodyItem::InlineTag(InlineTag::new("inheritDoc", "Foo"))
+        );
+        assert_eq!(
+            inline_tag_to_body_item(ParseConfig::default(), InlineTag::new("inheritDoc", "")),
+            BodyItem::InlineTag(InlineTag::new("inheritDoc", ""))
+        );
+    }
+
     #[test]
     fn test_inline_tag_body_line() {
-        assert_eq!(inline_tag_body_line("\n"), Ok(("", "\n")));
-        assert_eq!(inline_tag_body_line("Hello"), Ok(("", "Hello")));
-        assert_eq!(inline_tag_body_line("Hello\n"), Ok(("", "Hello\n")));
-        assert_eq!(inline_tag_body_line("Hello}"), Ok(("}", "Hello")));
         assert_eq!(
-            inline_tag_body_line("Hello { world"),
+            inline_tag_body_line(ParseConfig::default())("\n"),
+            Ok(("", "\n"))
+        );
+        assert_eq!(
+            inline_tag_body_line(ParseConfig::default())("Hello"),
+            Ok(("", "Hello"))
+        );
+        assert_eq!(
+            inline_tag_body_line(ParseConfig::default())("Hello\n"),
+            Ok(("", "Hello\n"))
+        );
+        assert_eq!(
+            inline_tag_body_line(ParseConfig::default())("Hello}"),
+            Ok(("}", "Hello"))
+        );
+        assert_eq!(
+            inline_tag_body_line(ParseConfig::default())("Hello { world"),
             Ok(("{ world", "Hello "))
         );
-        assert_eq!(inline_tag_body_line("He\\}llo}"), Ok(("}", "He\\}llo")));
         assert_eq!(
-            inline_tag_body_line("Hello \\{\\} world"),
+            inline_tag_body_line(ParseConfig::default())("He\\}llo}"),
+            Ok(("}", "He\\}llo"))
+        );
+        assert_eq!(
+            inline_tag_body_line(ParseConfig::default())("Hello \\{\\} world"),
             Ok(("", "Hello \\{\\} world"))
         );
 
         assert_eq!(
-            inline_tag_body_line(""),
+            inline_tag_body_line(ParseConfig::default())(""),
             Err(NomErr::Error(VerboseError {
                 errors: vec![
                     ("", VerboseErrorKind::Nom(ErrorKind::NonEmpty)),
@@ -418,7 +1125,7 @@ mod tests {
             }))
         );
         assert_eq!(
-            inline_tag_body_line("Hello \\ world"),
+            inline_tag_body_line(ParseConfig::default())("Hello \\ world"),
             Err(NomErr::Error(VerboseError {
                 errors: vec![
                     (" world", VerboseErrorKind::Nom(ErrorKind::OneOf)),
@@ -434,14 +1141,14 @@ mod tests {
 
     #[test]
     fn test_inline_tag_body() {
-        let input = r#"Hello
+        let input = r"Hello
         * world.
         * \{\}
         *
         * Second paragraph.
-        * }"#;
+        * }";
         assert_eq!(
-            inline_tag_body(input),
+            inline_tag_body(ParseConfig::default())(input),
             Ok((
                 "        * }",
                 vec![
@@ -458,7 +1165,7 @@ mod tests {
     #[test]
     fn test_inline_tag() {
         assert_eq!(
-            inline_tag("{@tag}"),
+            inline_tag(ParseConfig::default())("{@tag}"),
             Ok((
                 "",
                 InlineTag {
@@ -468,7 +1175,7 @@ mod tests {
             ))
         );
         assert_eq!(
-            inline_tag("{@tag body text}"),
+            inline_tag(ParseConfig::default())("{@tag body text}"),
             Ok((
                 "",
                 InlineTag {
@@ -478,7 +1185,7 @@ mod tests {
             ))
         );
         assert_eq!(
-            inline_tag("{@tag - body text}"),
+            inline_tag(ParseConfig::default())("{@tag - body text}"),
             Ok((
                 "",
                 InlineTag {
@@ -488,7 +1195,7 @@ mod tests {
             ))
         );
         assert_eq!(
-            inline_tag("{@tag \\{\\}}"),
+            inline_tag(ParseConfig::default())("{@tag \\{\\}}"),
             Ok((
                 "",
                 InlineTag {
@@ -498,7 +1205,7 @@ mod tests {
             ))
         );
         assert_eq!(
-            inline_tag("{@tag @body}"),
+            inline_tag(ParseConfig::default())("{@tag @body}"),
             Ok((
                 "",
                 InlineTag {
@@ -508,12 +1215,158 @@ mod tests {
             ))
         );
         assert_eq!(
-            inline_tag("{@tag\n * line 1\n * line 2}"),
+            inline_tag(ParseConfig::default())("{@tag\n * line 1\n * line 2}"),
+            Ok((
+                "",
+                InlineTag {
+                    name: "tag",
+                    body_lines: vec!["\n", "line 1\n", "line 2"]
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_take_until_either() {
+        struct Wrapper<'t> {
+            parser: TakeUntilEither<'t>,
+        }
+
+        let tokens = [Token::Escapable("{"), Token::NonEscapable("\n")];
+        let mut wrapper = Wrapper {
+            parser: take_until_either(&tokens, Escape::Char('\\')),
+        };
+        assert_eq!(
+            wrapper.parser.parse("Hello {world"),
+            Ok(("{world", "Hello "))
+        );
+        assert_eq!(
+            wrapper.parser.parse("Hello \\{world\n"),
+            Ok(("\n", "Hello \\{world"))
+        );
+        assert_eq!(wrapper.parser.parse("Hello"), Ok(("", "Hello")));
+    }
+
+    #[test]
+    fn test_take_until_either_escape() {
+        let tokens = [Token::Escapable("{"), Token::NonEscapable("\n")];
+
+        let mut parser = take_until_either(&tokens, Escape::Char('~'));
+        assert_eq!(parser.parse("a ~{b {c"), Ok(("{c", "a ~{b ")));
+        assert_eq!(parser.parse("a \\{b"), Ok(("{b", "a \\")));
+        assert_eq!(parser.parse("a ~~{b"), Ok(("{b", "a ~~")));
+
+        let mut parser = take_until_either(&tokens, Escape::Doubling);
+        assert_eq!(parser.parse("a {{b {c"), Ok(("{c", "a {{b ")));
+        assert_eq!(parser.parse("a {{{b"), Ok(("{b", "a {{")));
+        assert_eq!(parser.parse("a \\{b"), Ok(("{b", "a \\")));
+        assert_eq!(parser.parse("a {{\nb"), Ok(("\nb", "a {{")));
+    }
+
+    #[test]
+    fn test_body_kdoc_links() {
+        let config = ParseConfig {
+            parse_kdoc_links: true,
+            ..ParseConfig::default()
+        };
+        let link = |target| BodyItem::Link {
+            target,
+            label: None,
+        };
+
+        assert_eq!(
+            body(config)("See [Foo] for details").map(|(_, items)| items),
+            Ok(vec![
+                BodyItem::TextSegment("See "),
+                link("Foo"),
+                BodyItem::TextSegment("for details"),
+            ])
+        );
+        assert_eq!(
+            body(config)("[Foo.bar]").map(|(_, items)| items),
+            Ok(vec![link("Foo.bar")])
+        );
+        assert_eq!(
+            body(config)("Not \\[Foo\\] a link").map(|(_, items)| items),
+            Ok(vec![BodyItem::TextSegment("Not \\[Foo\\] a link")])
+        );
+        assert_eq!(
+            body(config)("a [ b [] c").map(|(_, items)| items),
+            Ok(vec![
+                BodyItem::TextSegment("a "),
+                BodyItem::TextSegment("["),
+                BodyItem::TextSegment("b "),
+                BodyItem::TextSegment("["),
+                BodyItem::TextSegment("] c"),
+            ])
+        );
+        assert_eq!(
+            body(ParseConfig::default())("See [Foo]").map(|(_, items)| items),
+            Ok(vec![BodyItem::TextSegment("See [Foo]")])
+        );
+    }
+
+    #[test]
+    fn test_strict_inline_brace() {
+        let strict = ParseConfig::default();
+        assert_eq!(
+            body_text_segment(strict)("returns {} or { a: 1 } {@link Foo}"),
+            Ok(("{@link Foo}", "returns {} or { a: 1 } "))
+        );
+        assert_eq!(
+            body(strict)("a {b} {@code c} d}").map(|(_, items)| items),
+            Ok(vec![
+                BodyItem::TextSegment("a {b} "),
+                BodyItem::InlineTag(InlineTag::new("code", "c")),
+                BodyItem::TextSegment("d}"),
+            ])
+        );
+
+        let lenient = ParseConfig {
+            strict_inline_brace: false,
+            ..ParseConfig::default()
+        };
+        assert_eq!(
+            body_text_segment(lenient)("returns {} {@link Foo}"),
+            Ok(("{} {@link Foo}", "returns "))
+        );
+        assert!(body(lenient)("{b}").is_err());
+    }
+
+    #[test]
+    fn test_escape_config() {
+        let config = ParseConfig {
+            escape_char: '!',
+            ..ParseConfig::default()
+        };
+        assert_eq!(
+            body_text_segment(config)("Hello !{@ world\n"),
+            Ok(("@ world\n", "Hello !{"))
+        );
+        assert_eq!(
+            inline_tag_body_line(config)("He!}llo}"),
+            Ok(("}", "He!}llo"))
+        );
+
+        let config = ParseConfig {
+            use_doubling_escape: true,
+            ..ParseConfig::default()
+        };
+        assert_eq!(
+            body_text_segment(config)("Hello {{@@ world}} {@"),
+            Ok(("{@", "Hello {{@@ world}} "))
+        );
+        assert_eq!(
+            inline_tag_body_line(config)("a {{b}} c}"),
+            Ok(("}", "a {{b}} c"))
+        );
+        assert_eq!(
+            inline_tag(config)("{@code {{}}}"),
             Ok((
                 "",
                 InlineTag {
-                    name: "tag",
-                    body_lines: vec!["\n", "line 1\n", "line 2"]
+                    name: "code",
+                    body_lines: vec!["{{}}"]
                 }
             ))
         );
@@ -521,37 +1374,44 @@ mod tests {
 
     #[test]
     fn test_body_text_segment() {
-        assert_eq!(body_text_segment("\n"), Ok(("", "\n")));
         assert_eq!(
-            body_text_segment("Hello {@ world\n"),
+            body_text_segment(ParseConfig::default())("\n"),
+            Ok(("", "\n"))
+        );
+        assert_eq!(
+            body_text_segment(ParseConfig::default())("Hello {@ world\n"),
             Ok(("{@ world\n", "Hello "))
         );
         assert_eq!(
-            body_text_segment("Hello */ world"),
+            body_text_segment(ParseConfig::default())("Hello */ world"),
             Ok(("*/ world", "Hello "))
         );
         assert_eq!(
-            body_text_segment("Hello \\{@ world\n"),
+            body_text_segment(ParseConfig::default())("Hello \\*/ world"),
+            Ok(("*/ world", "Hello \\"))
+        );
+        assert_eq!(
+            body_text_segment(ParseConfig::default())("Hello \\{@ world\n"),
             Ok(("@ world\n", "Hello \\{"))
         );
         assert_eq!(
-            body_text_segment("Hello \\{\\@ world\n"),
+            body_text_segment(ParseConfig::default())("Hello \\{\\@ world\n"),
             Ok(("", "Hello \\{\\@ world\n"))
         );
         assert_eq!(
-            body_text_segment("Hello \\\\{@ world\n"),
+            body_text_segment(ParseConfig::default())("Hello \\\\{@ world\n"),
             Ok(("{@ world\n", "Hello \\\\"))
         );
         assert_eq!(
-            body_text_segment("Hello \\\\\\{ world\n"),
+            body_text_segment(ParseConfig::default())("Hello \\\\\\{ world\n"),
             Ok(("", "Hello \\\\\\{ world\n"))
         );
         assert_eq!(
-            body_text_segment("Hello world\r\n"),
+            body_text_segment(ParseConfig::default())("Hello world\r\n"),
             Ok(("", "Hello world\r\n"))
         );
         assert_eq!(
-            body_text_segment(""),
+            body_text_segment(ParseConfig::default())(""),
             Err(NomErr::Error(VerboseError {
                 errors: vec![
                     ("", VerboseErrorKind::Nom(ErrorKind::Verify)),
@@ -561,7 +1421,7 @@ mod tests {
             }))
         );
         assert_eq!(
-            body_text_segment("   \t "),
+            body_text_segment(ParseConfig::default())("   \t "),
             Err(NomErr::Error(VerboseError {
                 errors: vec![
                     ("   \t ", VerboseErrorKind::Nom(ErrorKind::Verify)),
@@ -571,22 +1431,66 @@ mod tests {
             }))
         );
         assert_eq!(
-            body_text_segment("{"),
+            body_text_segment(ParseConfig::default())("@"),
             Err(NomErr::Error(VerboseError {
                 errors: vec![
-                    ("{", VerboseErrorKind::Nom(ErrorKind::Verify)),
-                    ("{", VerboseErrorKind::Nom(ErrorKind::Alt)),
-                    ("{", VerboseErrorKind::Context("body_text_segment"))
+                    ("@", VerboseErrorKind::Nom(ErrorKind::Verify)),
+                    ("@", VerboseErrorKind::Nom(ErrorKind::Alt)),
+                    ("@", VerboseErrorKind::Context("body_text_segment"))
                 ]
             }))
         );
+    }
+
+    #[test]
+    fn test_body_text_segment_braces() {
+        let lenient = ParseConfig {
+            strict_inline_brace: false,
+            ..ParseConfig::default()
+        };
+        assert_eq!(
+            body_text_segment(lenient)("Hello { world\n"),
+            Ok(("{ world\n", "Hello "))
+        );
+        assert_eq!(
+            body_text_segment(ParseConfig::default())("Hello { world\n"),
+            Ok(("", "Hello { world\n"))
+        );
+        assert_eq!(
+            body_text_segment(lenient)("Hello } world\n"),
+            Ok(("} world\n", "Hello "))
+        );
+        assert_eq!(
+            body_text_segment(ParseConfig::default())("Hello } world\n"),
+            Ok(("", "Hello } world\n"))
+        );
+        assert_eq!(
+            body_text_segment(lenient)("Hello \\{ world\n"),
+            Ok(("", "Hello \\{ world\n"))
+        );
+        assert_eq!(
+            body_text_segment(lenient)("Hello \\{\\} world\n"),
+            Ok(("", "Hello \\{\\} world\n"))
+        );
+        assert_eq!(
+            body_text_segment(lenient)("Hello \\\\{ world\n"),
+            Ok(("{ world\n", "Hello \\\\"))
+        );
+        assert_eq!(
+            body_text_segment(ParseConfig::default())("Hello \\\\{ world\n"),
+            Ok(("", "Hello \\\\{ world\n"))
+        );
+        assert_eq!(
+            body_text_segment(ParseConfig::default())("{"),
+            Ok(("", "{"))
+        );
         assert_eq!(
-            body_text_segment("@"),
+            body_text_segment(lenient)("{"),
             Err(NomErr::Error(VerboseError {
                 errors: vec![
-                    ("@", VerboseErrorKind::Nom(ErrorKind::Verify)),
-                    ("@", VerboseErrorKind::Nom(ErrorKind::Alt)),
-                    ("@", VerboseErrorKind::Context("body_text_segment"))
+                    ("{", VerboseErrorKind::Nom(ErrorKind::Verify)),
+                    ("{", VerboseErrorKind::Nom(ErrorKind::Alt)),
+                    ("{", VerboseErrorKind::Context("body_text_segment"))
                 ]
             }))
         );
@@ -595,13 +1499,13 @@ mod tests {
     #[test]
     fn test_description() {
         assert_eq!(
-            description(
-                r#"This is the description section
+            description(ParseConfig::default())(
+                r"This is the description section
             * that contains
             * multiple lines
             *
             * and paragraphs.
-            * @blockTag"#
+            * @blockTag"
             ),
             Ok((
                 "@blockTag",
@@ -617,10 +1521,10 @@ mod tests {
             ))
         );
         assert_eq!(
-            description(
-                r#"This is the description section
+            description(ParseConfig::default())(
+                r"This is the description section
             * that contains both text segments and {@inlineTag}.
-            * @blockTag"#
+            * @blockTag"
             ),
             Ok((
                 "@blockTag",
@@ -638,12 +1542,12 @@ mod tests {
             ))
         );
         assert_eq!(
-            description(
-                r#"This is the description section
+            description(ParseConfig::default())(
+                r"This is the description section
             * that contains multi-line {@inlineTag
             * tag body
             * }
-            * @blockTag"#
+            * @blockTag"
             ),
             Ok((
                 "@blockTag",
@@ -661,7 +1565,7 @@ mod tests {
             ))
         );
         assert_eq!(
-            description("{@inlineTag with body}    \n"),
+            description(ParseConfig::default())("{@inlineTag with body}    \n"),
             Ok((
                 "",
                 Description {
@@ -680,64 +1584,70 @@ mod tests {
     #[test]
     fn test_block_tag() {
         assert_eq!(
-            block_tag("@blockTag "),
+            block_tag(ParseConfig::default())("@blockTag "),
             Ok((
                 "",
                 BlockTag {
                     name: "blockTag",
+                    position: 0,
                     body_items: vec![]
                 }
             ))
         );
         assert_eq!(
-            block_tag("@blockTag*/"),
+            block_tag(ParseConfig::default())("@blockTag*/"),
             Ok((
                 "*/",
                 BlockTag {
                     name: "blockTag",
+                    position: 0,
                     body_items: vec![]
                 }
             ))
         );
         assert_eq!(
-            block_tag("@blockTag with body */"),
+            block_tag(ParseConfig::default())("@blockTag with body */"),
             Ok((
                 "*/",
                 BlockTag {
                     name: "blockTag",
+                    position: 0,
                     body_items: vec![BodyItem::TextSegment("with body ")]
                 }
             ))
         );
         assert_eq!(
-            block_tag(r#"@blockTag with body @anotherBlockTag"#),
+            block_tag(ParseConfig::default())(r"@blockTag with body @anotherBlockTag"),
             Ok((
                 "@anotherBlockTag",
                 BlockTag {
                     name: "blockTag",
+                    position: 0,
                     body_items: vec![BodyItem::TextSegment("with body ")]
                 }
             ))
         );
         assert_eq!(
-            block_tag(
-                r#"@blockTag with body
-                * @anotherBlockTag"#
+            block_tag(ParseConfig::default())(
+                r"@blockTag with body
+                * @anotherBlockTag"
             ),
             Ok((
                 "@anotherBlockTag",
                 BlockTag {
                     name: "blockTag",
+                    position: 0,
                     body_items: vec![BodyItem::TextSegment("with body\n")]
                 }
             ))
         );
         assert_eq!(
-            block_tag("@blockTag {@inlineTag}"),
+            block_tag(ParseConfig::default())("@blockTag {@inlineTag}"),
             Ok((
                 "",
                 BlockTag {
                     name: "blockTag",
+                    position: 0,
                     body_items: vec![BodyItem::InlineTag(InlineTag {
                         name: "inlineTag",
                         body_lines: vec![]
@@ -745,12 +1655,32 @@ mod tests {
                 }
             ))
         );
+        assert_eq!(
+            block_tag(ParseConfig::default())(
+                r"@param foo
+                *   continuation text
+                *   more continuation text
+                * @anotherBlockTag"
+            ),
+            Ok((
+                "@anotherBlockTag",
+                BlockTag {
+                    name: "param",
+                    position: 0,
+                    body_items: vec![
+                        BodyItem::TextSegment("foo\n"),
+                        BodyItem::TextSegment("continuation text\n"),
+                        BodyItem::TextSegment("more continuation text\n"),
+                    ]
+                }
+            ))
+        );
     }
 
     #[test]
     fn test_comment_empty() {
         assert_eq!(
-            doc_comment("/** */"),
+            doc_comment(ParseConfig::default())("/** */"),
             Ok((
                 "",
                 DocComment {
@@ -764,7 +1694,7 @@ mod tests {
     #[test]
     fn test_comment_one_line_description() {
         assert_eq!(
-            doc_comment("/** One-line description. */"),
+            doc_comment(ParseConfig::default())("/** One-line description. */"),
             Ok((
                 "",
                 DocComment {
@@ -776,7 +1706,9 @@ mod tests {
             ))
         );
         assert_eq!(
-            doc_comment("/** One-line description containing {@inlineTag} */"),
+            doc_comment(ParseConfig::default())(
+                "/** One-line description containing {@inlineTag} */"
+            ),
             Ok((
                 "",
                 DocComment {
@@ -794,7 +1726,7 @@ mod tests {
             ))
         );
         assert_eq!(
-            doc_comment(
+            doc_comment(ParseConfig::default())(
                 "/** One-line description containing {@inlineTag} and some text after it. */"
             ),
             Ok((
@@ -815,7 +1747,9 @@ mod tests {
             ))
         );
         assert_eq!(
-            doc_comment("/** One-line description containing {@inlineTag with body} */"),
+            doc_comment(ParseConfig::default())(
+                "/** One-line description containing {@inlineTag with body} */"
+            ),
             Ok((
                 "",
                 DocComment {
@@ -837,11 +1771,11 @@ mod tests {
     #[test]
     fn test_comment_multi_line() {
         assert_eq!(
-            doc_comment(
-                r#"/**
+            doc_comment(ParseConfig::default())(
+                r"/**
                 * This is a description-only comment.
                 * The description contains an {@inlineTag} though.
-                */"#
+                */"
             ),
             Ok((
                 "",
@@ -863,18 +1797,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_comment_block_tag_continuation_lines() {
+        assert_eq!(
+            doc_comment(ParseConfig::default())(
+                r"/**
+                * @param foo
+                *   continuation text
+                *   with {@inlineTag} inside
+                * @returns bar
+                */"
+            ),
+            Ok((
+                "",
+                DocComment {
+                    description: None,
+                    block_tags: vec![
+                        BlockTag {
+                            name: "param",
+                            position: 0,
+                            body_items: vec![
+                                BodyItem::TextSegment("foo\n"),
+                                BodyItem::TextSegment("continuation text\n"),
+                                BodyItem::TextSegment("with "),
+                                BodyItem::InlineTag(InlineTag {
+                                    name: "inlineTag",
+                                    body_lines: vec![]
+                                }),
+                                BodyItem::TextSegment("inside\n"),
+                            ]
+                        },
+                        BlockTag {
+                            name: "returns",
+                            position: 1,
+                            body_items: vec![BodyItem::TextSegment("bar\n")]
+                        },
+                    ]
+                }
+            ))
+        );
+    }
+
     #[test]
     fn test_comment_all_elements() {
         assert_eq!(
-            doc_comment(
-                r#"/**
+            doc_comment(ParseConfig::default())(
+                r"/**
                 * This is a doc comment.
                 * It contains an {@inlineTag with some body} in its description.
                 *
                 * @blockTag1
                 * @blockTag2 with body text
                 * @blockTag3 with body text and {@inlineTag}
-                */"#
+                */"
             ),
             Ok((
                 "",
@@ -894,14 +1869,17 @@ mod tests {
                     block_tags: vec![
                         BlockTag {
                             name: "blockTag1",
+                            position: 0,
                             body_items: vec![]
                         },
                         BlockTag {
                             name: "blockTag2",
+                            position: 1,
                             body_items: vec![BodyItem::TextSegment("with body text\n"),]
                         },
                         BlockTag {
                             name: "blockTag3",
+                            position: 2,
                             body_items: vec![
                                 BodyItem::TextSegment("with body text and "),
                                 BodyItem::InlineTag(InlineTag {
@@ -914,6 +1892,356 @@ mod tests {
                     ]
                 }
             ))
+        );
+    }
+    #[test]
+    fn test_comment_without_line_leading() {
+        let config = ParseConfig {
+            require_line_leading: false,
+            ..ParseConfig::default()
+        };
+
+        assert_eq!(
+            doc_comment(config)(
+                "/**\nFirst paragraph.\n\nSecond paragraph with {@inlineTag\nmulti-line body}.\n   \t\nThird paragraph.\n  @param foo bar\n*/"
+            ),
+            Ok((
+                "",
+                DocComment {
+                    description: Some(Description {
+                        body_items: vec![
+                            BodyItem::TextSegment("First paragraph.\n"),
+                            BodyItem::TextSegment("\n"),
+                            BodyItem::TextSegment("Second paragraph with "),
+                            BodyItem::InlineTag(InlineTag {
+                                name: "inlineTag",
+                                body_lines: vec!["\n", "multi-line body"]
+                            }),
+                            BodyItem::TextSegment(".\n"),
+                            BodyItem::TextSegment("\n"),
+                            BodyItem::TextSegment("Third paragraph.\n"),
+                        ]
+                    }),
+                    block_tags: vec![BlockTag {
+                        name: "param",
+                        position: 0,
+                        body_items: vec![BodyItem::TextSegment("foo bar\n")]
+                    }]
+                }
+            ))
+        );
+        assert_eq!(
+            doc_comment(config)("/**\n  @param foo\n  @returns bar\n*/"),
+            Ok((
+                "",
+                DocComment {
+                    description: None,
+                    block_tags: vec![
+                        BlockTag {
+                            name: "param",
+                            position: 0,
+                            body_items: vec![BodyItem::TextSegment("foo\n")]
+                        },
+                        BlockTag {
+                            name: "returns",
+                            position: 1,
+                            body_items: vec![BodyItem::TextSegment("bar\n")]
+                        }
+                    ]
+                }
+            ))
+        );
+        assert!(doc_comment(ParseConfig::default())(
+            "/**\nSecond paragraph with {@inlineTag\nmulti-line body}.\n*/"
         )
+        .is_err());
+    }
+    #[test]
+    fn test_body_code_spans() {
+        let config = ParseConfig {
+            parse_code_spans: true,
+            ..ParseConfig::default()
+        };
+
+        assert_eq!(
+            body(config)("Call {@code foo(bar)} or {@code}, see {@link Foo}.\n"),
+            Ok((
+                "",
+                vec![
+                    BodyItem::TextSegment("Call "),
+                    BodyItem::CodeSpan("foo(bar)"),
+                    BodyItem::TextSegment("or "),
+                    BodyItem::CodeSpan(""),
+                    BodyItem::TextSegment(", see "),
+                    BodyItem::InlineTag(InlineTag {
+                        name: "link",
+                        body_lines: vec!["Foo"]
+                    }),
+                    BodyItem::TextSegment(".\n"),
+                ]
+            ))
+        );
+        assert_eq!(
+            body(config)("{@code multi\n * line}"),
+            Ok((
+                "",
+                vec![BodyItem::InlineTag(InlineTag {
+                    name: "code",
+                    body_lines: vec!["multi\n", "line"]
+                })]
+            ))
+        );
+        assert_eq!(
+            body(ParseConfig::default())("{@code foo}"),
+            Ok((
+                "",
+                vec![BodyItem::InlineTag(InlineTag {
+                    name: "code",
+                    body_lines: vec!["foo"]
+                })]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_block_tag_prop_type() {
+        let config = ParseConfig {
+            parse_prop_tags: true,
+            ..ParseConfig::default()
+        };
+
+        assert_eq!(
+            block_tag(config)("@prop {Array<string>} items - the items\n"),
+            Ok((
+                "",
+                BlockTag {
+                    name: "prop",
+                    position: 0,
+                    body_items: vec![
+                        BodyItem::TextSegment("{Array<string>}"),
+                        BodyItem::TextSegment("items - the items\n"),
+                    ]
+                }
+            ))
+        );
+        assert_eq!(
+            block_tag(config)("@prop {boolean}"),
+            Ok((
+                "",
+                BlockTag {
+                    name: "prop",
+                    position: 0,
+                    body_items: vec![BodyItem::TextSegment("{boolean}")]
+                }
+            ))
+        );
+        assert_eq!(
+            block_tag(config)("@param {boolean} flag").map(|(_, tag)| tag.body_items),
+            Ok(vec![BodyItem::TextSegment("{boolean} flag")])
+        );
+        assert_eq!(
+            block_tag(ParseConfig {
+                strict_inline_brace: false,
+                ..config
+            })("@param {boolean} flag")
+            .map(|(rest, _)| rest),
+            Ok("{boolean} flag")
+        );
+        assert_eq!(
+            block_tag(ParseConfig {
+                parse_type_expressions: true,
+                ..ParseConfig::default()
+            })("@param {boolean} flag"),
+            Ok((
+                "",
+                BlockTag {
+                    name: "param",
+                    position: 0,
+                    body_items: vec![
+                        BodyItem::TextSegment("{boolean}"),
+                        BodyItem::TextSegment("flag"),
+                    ]
+                }
+            ))
+        );
+        assert_eq!(
+            block_tag(ParseConfig::default())("@prop {boolean} flag")
+                .map(|(_, tag)| tag.body_items),
+            Ok(vec![BodyItem::TextSegment("{boolean} flag")])
+        );
+    }
+
+    #[test]
+    fn test_example_caption() {
+        let config = ParseConfig {
+            parse_example_titles: true,
+            ..ParseConfig::default()
+        };
+        assert_eq!(
+            block_tag(config)("@example <caption>Usage</caption> foo()")
+                .map(|(_, tag)| tag.body_items),
+            Ok(vec![
+                BodyItem::TextSegment("<caption>Usage</caption>"),
+                BodyItem::TextSegment("foo()"),
+            ])
+        );
+        assert_eq!(
+            block_tag(config)("@param <caption>Usage</caption> foo()")
+                .map(|(_, tag)| tag.body_items),
+            Ok(vec![BodyItem::TextSegment(
+                "<caption>Usage</caption> foo()"
+            )])
+        );
+        assert_eq!(
+            block_tag(config)("@example <caption>Usage\n * </caption> foo()")
+                .map(|(_, tag)| tag.body_items),
+            Ok(vec![
+                BodyItem::TextSegment("<caption>Usage\n"),
+                BodyItem::TextSegment("</caption> foo()"),
+            ])
+        );
+        assert_eq!(
+            block_tag(ParseConfig::default())("@example <caption>Usage</caption> foo()")
+                .map(|(_, tag)| tag.body_items),
+            Ok(vec![BodyItem::TextSegment(
+                "<caption>Usage</caption> foo()"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_nested_inline_tags() {
+        let input = "{@link Foo {@code bar} baz}";
+        assert_eq!(
+            inline_tag(ParseConfig::default())(input),
+            Ok((
+                "",
+                InlineTag {
+                    name: "link",
+                    body_lines: vec!["Foo {@code bar} baz"],
+                }
+            ))
+        );
+        assert_eq!(
+            inline_tag(ParseConfig::default())(r"{@link {@code {@b \} x}} y}"),
+            Ok((
+                "",
+                InlineTag {
+                    name: "link",
+                    body_lines: vec![r"{@code {@b \} x}} y"],
+                }
+            ))
+        );
+        assert!(inline_tag(ParseConfig::default())("{@link {foo}}").is_err());
+        assert!(inline_tag(ParseConfig::default())("{@link {@code bar}").is_err());
+
+        let config = ParseConfig {
+            max_inline_tag_depth: 2,
+            ..ParseConfig::default()
+        };
+        assert_eq!(
+            inline_tag(config)(input),
+            Ok((
+                "",
+                InlineTag {
+                    name: "link",
+                    body_lines: vec!["Foo {@code bar} baz"],
+                }
+            ))
+        );
+        assert_eq!(
+            inline_tag(config)("{@link {@code}}"),
+            Ok((
+                "",
+                InlineTag {
+                    name: "link",
+                    body_lines: vec!["{@code}"],
+                }
+            ))
+        );
+        // Past the limit, the innermost tag is kept as text without being parsed.
+        assert_eq!(
+            inline_tag(config)("{@link {@code {@b x}}}"),
+            inline_tag(ParseConfig {
+                max_inline_tag_depth: 3,
+                ..config
+            })("{@link {@code {@b x}}}")
+        );
+        assert!(inline_tag(config)("{@link {@code {b x}}}").is_err());
+        assert!(inline_tag(ParseConfig {
+            max_inline_tag_depth: 3,
+            ..config
+        })("{@link {@code {@b x}}}")
+        .is_ok());
+    }
+
+    #[test]
+    fn test_code_block() {
+        assert_eq!(
+            code_block("```rust\n * let a = 1;\n *     indented\n *\n * ```\n * rest"),
+            Ok((
+                " * rest",
+                BodyItem::CodeBlock {
+                    language: Some("rust"),
+                    lines: vec!["let a = 1;\n", "    indented\n", "\n"],
+                }
+            ))
+        );
+        assert_eq!(
+            code_block("````\nno leading\n````"),
+            Ok((
+                "",
+                BodyItem::CodeBlock {
+                    language: None,
+                    lines: vec!["no leading\n"],
+                }
+            ))
+        );
+        assert!(code_block("```\n * unterminated\n */").is_err());
+    }
+
+    #[test]
+    fn test_body_code_blocks() {
+        let config = ParseConfig {
+            parse_code_blocks: true,
+            ..ParseConfig::default()
+        };
+        let input = "Example:\n * ```ts\n * foo(@bar);\n * ```\n * Done.\n";
+
+        assert_eq!(
+            body(config)(input),
+            Ok((
+                "",
+                vec![
+                    BodyItem::TextSegment("Example:\n"),
+                    BodyItem::CodeBlock {
+                        language: Some("ts"),
+                        lines: vec!["foo(@bar);\n"],
+                    },
+                    BodyItem::TextSegment("Done.\n"),
+                ]
+            ))
+        );
+        assert_eq!(
+            body(config)("```\n * unterminated\n"),
+            Ok((
+                "",
+                vec![
+                    BodyItem::TextSegment("```\n"),
+                    BodyItem::TextSegment("unterminated\n"),
+                ]
+            ))
+        );
+        assert_eq!(
+            body(ParseConfig::default())("```\n * code\n * ```\n"),
+            Ok((
+                "",
+                vec![
+                    BodyItem::TextSegment("```\n"),
+                    BodyItem::TextSegment("code\n"),
+                    BodyItem::TextSegment("```\n"),
+                ]
+            ))
+        );
     }
 }
@@ -0,0 +1,210 @@
+//! Canonical formatting of `/** */` doc comments, e.g. for comment auto-formatters.
+
+use crate::ast::{BlockTag, BodyItem, DocComment};
+use crate::config::FormatConfig;
+
+use super::{
+    body_to_string, code_block_to_fenced, inline_tag_to_string, to_indented_block_comment,
+    INHERIT_DOC,
+};
+
+/// Length of the ` * ` line leading.
+const LINE_LEADING_LEN: usize = 3;
+
+/// Renders `doc` as a canonically formatted `/** */` comment according to `config`.
+///
+/// Every line gets the ` * ` line leading, the description is separated from
+/// the block tags with an empty line and inline tags are written as `{@name body}`.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse;
+/// use doctor::config::FormatConfig;
+/// use doctor::render::format::format_doc_comment;
+///
+/// let doc = parse("/** Finds the user.\n@returns the user\n@param id the id */").unwrap();
+/// let config = FormatConfig {
+///     indent: "  ".to_owned(),
+///     sort_tags: true,
+///     tag_order: vec!["param".to_owned(), "returns".to_owned()],
+///     ..FormatConfig::default()
+/// };
+///
+/// assert_eq!(
+///     format_doc_comment(&doc, &config),
+///     "  /**
+///    * Finds the user.
+///    *
+///    * @param id the id
+///    * @returns the user
+///    */"
+/// );
+/// ```
+#[must_use]
+pub fn format_doc_comment(doc: &DocComment<'_>, config: &FormatConfig) -> String {
+    let description = doc.description.as_ref().map(|description| {
+        let text = body_to_string(&description.body_items, item_to_string);
+        match config.max_line_length {
+            Some(max_line_length) => wrap_lines(
+                &text,
+                max_line_length.saturating_sub(config.indent.len() + LINE_LEADING_LEN),
+            ),
+            None => text,
+        }
+    });
+
+    let mut block_tags: Vec<_> = doc.block_tags.iter().collect();
+    if config.sort_tags {
+        block_tags.sort_by_key(|tag| {
+            config
+                .tag_order
+                .iter()
+                .position(|name| name == tag.name)
+                .unwrap_or(config.tag_order.len())
+        });
+    }
+    let block_tags = block_tags
+        .into_iter()
+        .map(block_tag_to_string)
+        .collect::<String>();
+
+    let sections = description
+        .into_iter()
+        .chain(Some(block_tags).filter(|block_tags| !block_tags.is_empty()));
+    to_indented_block_comment(&config.indent, sections)
+}
+
+impl DocComment<'_> {
+    /// Renders the comment as a canonically formatted `/** */` comment according to `config`.
+    ///
+    /// See [`format_doc_comment`] for details.
+    #[must_use]
+    pub fn format(&self, config: &FormatConfig) -> String {
+        format_doc_comment(self, config)
+    }
+}
+
+fn block_tag_to_string(tag: &BlockTag<'_>) -> String {
+    let body = body_to_string(&tag.body_items, item_to_string);
+    let body = body.trim_end();
+    if body.is_empty() {
+        format!("@{}\n", tag.name)
+    } else {
+        format!("@{} {}\n", tag.name, body)
+    }
+}
+
+fn item_to_string(item: &BodyItem<'_>) -> String {
+    match item {
+        BodyItem::InlineTag(tag) => inline_tag_to_string(tag),
+        BodyItem::CodeSpan("") => "{@code}".to_owned(),
+        BodyItem::CodeSpan(code) => format!("{{@code {code}}}"),
+        BodyItem::CodeBlock { language, lines } => code_block_to_fenced(*language, lines),
+        BodyItem::Link {
+            target,
+            label: None,
+        } => format!("{{@link {target}}}"),
+        BodyItem::Link {
+            target,
+            label: Some(label),
+        } => format!("{{@link {target} {label}}}"),
+        BodyItem::InheritDoc => INHERIT_DOC.to_owned(),
+        BodyItem::TextSegment(s) => (*s).to_owned(),
+    }
+}
+
+/// Wraps the lines of `text` longer than `width` at word boundaries.
+/// Words longer than `width` are put on a line of their own.
+/// Lines of fenced code blocks are never wrapped.
+fn wrap_lines(text: &str, width: usize) -> String {
+    let mut result = String::new();
+    let mut in_code_block = false;
+    for line in text.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        if in_code_block || line.len() <= width {
+            result.push_str(line);
+            result.push('\n');
+            continue;
+        }
+
+        let mut line_len = 0;
+        for word in line.split_whitespace() {
+            if line_len > 0 && line_len + 1 + word.len() > width {
+                result.push('\n');
+                line_len = 0;
+            }
+            if line_len > 0 {
+                result.push(' ');
+                line_len += 1;
+            }
+            result.push_str(word);
+            line_len += word.len();
+        }
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_format_doc_comment() {
+        let doc = parse(
+            r"/**
+               * A {@link Foo} description that is long enough to be wrapped.
+               * Short line.
+               *
+               * @since 1.0
+               * @throws Error
+               * @param foo the {@code foo}
+               */",
+        )
+        .unwrap();
+
+        assert_eq!(
+            format_doc_comment(&doc, &FormatConfig::default()),
+            "/**
+ * A {@link Foo} description that is long enough to be wrapped.
+ * Short line.
+ *
+ * @since 1.0
+ * @throws Error
+ * @param foo the {@code foo}
+ */"
+        );
+        assert_eq!(
+            doc.format(&FormatConfig {
+                indent: "    ".to_owned(),
+                max_line_length: Some(40),
+                sort_tags: true,
+                tag_order: vec!["param".to_owned(), "throws".to_owned()],
+            }),
+            "    /**
+     * A {@link Foo} description that is
+     * long enough to be wrapped.
+     * Short line.
+     *
+     * @param foo the {@code foo}
+     * @throws Error
+     * @since 1.0
+     */"
+        );
+    }
+
+    #[test]
+    fn test_wrap_lines() {
+        assert_eq!(wrap_lines("a bb ccc\n", 4), "a bb\nccc\n");
+        assert_eq!(wrap_lines("short\n\nline\n", 5), "short\n\nline\n");
+        assert_eq!(wrap_lines("unbreakable word", 5), "unbreakable\nword\n");
+        assert_eq!(
+            wrap_lines("```\nlet a = b;\n```\nwrap me\n", 4),
+            "```\nlet a = b;\n```\nwrap\nme\n"
+        );
+    }
+}
@@ -0,0 +1,149 @@
+//! Renderer for [KDoc](https://kotlinlang.org/docs/kotlin-doc.html), the Kotlin doc comment format.
+
+use crate::ast::{BlockTag, BodyItem, DocComment};
+
+use super::{
+    body_to_string, code_block_to_fenced, inline_tag_to_string, link_to_reference, reference_link,
+    to_block_comment, INHERIT_DOC,
+};
+
+/// Renders `doc` as a KDoc comment.
+///
+/// `{@link Foo}` inline tags are translated to `[Foo]` references
+/// (or `[label][Foo]` when the link has a label), code spans to `` `code` ``,
+/// `@returns` becomes `@return`, other tags are kept as they are.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse;
+/// use doctor::render::kdoc::to_kdoc_string;
+///
+/// let doc = parse(r#"/**
+///     * Returns the {@link Foo} instance.
+///     *
+///     * @param id the identifier
+///     * @returns the found instance
+///     */"#).unwrap();
+///
+/// assert_eq!(
+///     to_kdoc_string(&doc),
+///     r#"/**
+///  * Returns the [Foo] instance.
+///  *
+///  * @param id the identifier
+///  * @return the found instance
+///  */"#
+/// );
+/// ```
+#[must_use]
+pub fn to_kdoc_string(doc: &DocComment<'_>) -> String {
+    let description = doc
+        .description
+        .as_ref()
+        .map(|description| body_to_string(&description.body_items, item_to_kdoc));
+    let block_tags = doc
+        .block_tags
+        .iter()
+        .map(block_tag_to_kdoc)
+        .collect::<String>();
+
+    let sections = description
+        .into_iter()
+        .chain(Some(block_tags).filter(|block_tags| !block_tags.is_empty()));
+    to_block_comment(sections)
+}
+
+fn block_tag_to_kdoc(tag: &BlockTag<'_>) -> String {
+    let name = match tag.name {
+        "returns" => "return",
+        name => name,
+    };
+    let body = body_to_string(&tag.body_items, item_to_kdoc);
+    let body = body.trim_end();
+    if body.is_empty() {
+        format!("@{name}\n")
+    } else {
+        format!("@{name} {body}\n")
+    }
+}
+
+fn item_to_kdoc(item: &BodyItem<'_>) -> String {
+    match item {
+        BodyItem::InlineTag(tag) if tag.name == "link" => link_to_reference(tag),
+        BodyItem::InlineTag(tag) => inline_tag_to_string(tag),
+        BodyItem::CodeSpan(code) => format!("`{code}`"),
+        BodyItem::CodeBlock { language, lines } => code_block_to_fenced(*language, lines),
+        BodyItem::Link { target, label } => reference_link(target, *label),
+        BodyItem::InheritDoc => INHERIT_DOC.to_owned(),
+        BodyItem::TextSegment(s) => (*s).to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ParseConfig;
+    use crate::{parse, parse_with_config};
+
+    #[test]
+    fn test_to_kdoc_string() {
+        assert_eq!(to_kdoc_string(&DocComment::default()), "/**\n */");
+        assert_eq!(
+            to_kdoc_string(&parse("/** See {@link Foo the foo} and {@code bar}. */").unwrap()),
+            "/**\n * See [the foo][Foo] and {@code bar}.\n */"
+        );
+        assert_eq!(
+            to_kdoc_string(
+                &parse_with_config(
+                    "/** Call {@code bar()}. */",
+                    ParseConfig {
+                        parse_code_spans: true,
+                        ..ParseConfig::default()
+                    }
+                )
+                .unwrap()
+            ),
+            "/**\n * Call `bar()`.\n */"
+        );
+        assert_eq!(
+            to_kdoc_string(
+                &parse_with_config(
+                    "/**\n * Example:\n * ```kotlin\n *     foo()\n * ```\n * Done.\n */",
+                    ParseConfig {
+                        parse_code_blocks: true,
+                        ..ParseConfig::default()
+                    }
+                )
+                .unwrap()
+            ),
+            "/**\n * Example:\n * ```kotlin\n *     foo()\n * ```\n * Done.\n */"
+        );
+    }
+
+    #[test]
+    fn test_to_kdoc_string_links() {
+        let doc =
+            crate::style::tsdoc::parse_tsdoc("/** See {@link Foo} and {@link Bar | the bar}. */")
+                .unwrap();
+        assert_eq!(
+            to_kdoc_string(&doc),
+            "/**\n * See [Foo] and [the bar][Bar].\n */"
+        );
+    }
+
+    #[test]
+    fn test_to_kdoc_string_round_trip() {
+        for input in [
+            "/**\n * Description.\n */",
+            "/**\n * Multi-line\n * description.\n *\n * Second paragraph with {@code inline tag}.\n */",
+            "/**\n * Description.\n *\n * @param foo the foo\n * @throws Error when failed\n */",
+            "/**\n * @param foo\n * @return the result\n */",
+        ] {
+            let doc = parse(input).unwrap();
+            let kdoc = to_kdoc_string(&doc);
+            assert_eq!(kdoc, input);
+            assert_eq!(parse(&kdoc), Ok(doc));
+        }
+    }
+}
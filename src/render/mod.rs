@@ -0,0 +1,156 @@
+//! Renderers converting a parsed [`DocComment`](crate::ast::DocComment) into other doc comment formats.
+
+pub mod format;
+pub mod kdoc;
+pub mod rustdoc;
+pub mod swagger;
+pub mod text;
+pub mod tsdoc;
+
+use crate::ast::{BodyItem, InlineTag};
+
+/// Text of the `{@inheritDoc}` marker, which every renderer passes through as is.
+const INHERIT_DOC: &str = "{@inheritDoc}";
+
+/// Concatenates `body_items` into a single string.
+/// Text segments are copied as they are, any other item is rendered with `render_item`.
+pub(crate) fn body_to_string(
+    body_items: &[BodyItem<'_>],
+    mut render_item: impl FnMut(&BodyItem<'_>) -> String,
+) -> String {
+    let mut result = String::new();
+    let mut after_tag = false;
+    for item in body_items {
+        if let BodyItem::TextSegment(s) = item {
+            // The parser drops the whitespace following an inline tag, so restore it
+            // unless the text continues with a punctuation mark.
+            if after_tag
+                && !result.ends_with('\n')
+                && s.starts_with(|ch: char| !ch.is_ascii_punctuation() && ch != '\n')
+            {
+                result.push(' ');
+            }
+            result.push_str(s);
+            after_tag = false;
+        } else {
+            result.push_str(&render_item(item));
+            after_tag = true;
+        }
+    }
+    result
+}
+
+/// Renders a non-text body item the way it would appear in plain text:
+/// inline tags are replaced with their body, code spans and blocks with their content
+/// and links with their label, if any, or target.
+/// `{@inheritDoc}` markers are kept as they are, to be resolved by the consumer.
+pub(crate) fn item_to_plain_text(item: &BodyItem<'_>) -> String {
+    match item {
+        BodyItem::TextSegment(s) | BodyItem::CodeSpan(s) => (*s).to_owned(),
+        BodyItem::InlineTag(tag) => tag.body_lines.concat(),
+        BodyItem::CodeBlock { lines, .. } => lines.concat(),
+        BodyItem::Link { target, label } => label.unwrap_or(target).to_owned(),
+        BodyItem::InheritDoc => INHERIT_DOC.to_owned(),
+    }
+}
+
+/// Splits the first word off the body and returns it along with the text of the rest of the body.
+pub(crate) fn split_first_word<'a>(body_items: &[BodyItem<'a>]) -> (&'a str, String) {
+    match body_items.split_first() {
+        Some((BodyItem::TextSegment(s), rest)) => {
+            let s = s.trim_start();
+            let (word, remainder) = s.split_at(s.find(char::is_whitespace).unwrap_or(s.len()));
+            let description = format!("{}{}", remainder, plain_text(rest));
+            (word, description.trim().to_owned())
+        }
+        _ => ("", plain_text(body_items)),
+    }
+}
+
+/// Splits the leading `{Type}` expression off the body, returning the type without the braces
+/// along with the rest of the body.
+pub(crate) fn split_type_expression<'a, 'b>(
+    body_items: &'b [BodyItem<'a>],
+) -> (Option<&'a str>, &'b [BodyItem<'a>]) {
+    match body_items.split_first() {
+        Some((BodyItem::TextSegment(s), rest)) if s.starts_with('{') && s.ends_with('}') => {
+            (Some(s[1..s.len() - 1].trim()), rest)
+        }
+        _ => (None, body_items),
+    }
+}
+
+/// Renders `body_items` as trimmed plain text.
+pub(crate) fn plain_text(body_items: &[BodyItem<'_>]) -> String {
+    body_to_string(body_items, item_to_plain_text)
+        .trim()
+        .to_owned()
+}
+
+/// Renders a code block as a Markdown fenced code block.
+fn code_block_to_fenced(language: Option<&str>, lines: &[&str]) -> String {
+    format!("```{}\n{}```\n", language.unwrap_or(""), lines.concat())
+}
+
+/// Renders an inline tag as is, i.e. `{@name body}`.
+fn inline_tag_to_string(tag: &InlineTag<'_>) -> String {
+    let body = tag.body_lines.concat();
+    if body.is_empty() {
+        format!("{{@{}}}", tag.name)
+    } else {
+        format!("{{@{} {}}}", tag.name, body)
+    }
+}
+
+/// Renders a `{@link reference label}` inline tag as a Markdown reference link,
+/// i.e. `[reference]` or `[label][reference]`.
+fn link_to_reference(tag: &InlineTag<'_>) -> String {
+    let body = tag.body_lines.concat();
+    let body = body.trim();
+    match body.split_once(char::is_whitespace) {
+        Some((reference, label)) => reference_link(reference, Some(label.trim())),
+        None => reference_link(body, None),
+    }
+}
+
+/// Renders a Markdown reference link, i.e. `[reference]` or `[label][reference]`.
+fn reference_link(reference: &str, label: Option<&str>) -> String {
+    label.map_or_else(
+        || format!("[{reference}]"),
+        |label| format!("[{label}][{reference}]"),
+    )
+}
+
+/// Wraps `sections` into a `/** */` comment, prefixing every line with ` * `.
+/// Sections are separated with an empty line.
+fn to_block_comment(sections: impl IntoIterator<Item = impl AsRef<str>>) -> String {
+    to_indented_block_comment("", sections)
+}
+
+/// Same as [`to_block_comment`], but every line of the comment is prefixed with `indent`.
+fn to_indented_block_comment(
+    indent: &str,
+    sections: impl IntoIterator<Item = impl AsRef<str>>,
+) -> String {
+    let mut result = format!("{indent}/**\n");
+    for (index, section) in sections.into_iter().enumerate() {
+        if index > 0 {
+            result.push_str(indent);
+            result.push_str(" *\n");
+        }
+        for line in section.as_ref().trim_end().lines() {
+            let line = line.trim_end();
+            result.push_str(indent);
+            if line.is_empty() {
+                result.push_str(" *\n");
+            } else {
+                result.push_str(" * ");
+                result.push_str(line);
+                result.push('\n');
+            }
+        }
+    }
+    result.push_str(indent);
+    result.push_str(" */");
+    result
+}
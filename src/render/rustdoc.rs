@@ -0,0 +1,227 @@
+//! Renderer for [rustdoc](https://doc.rust-lang.org/rustdoc/) `///` line doc comments.
+
+use crate::ast::{BlockTag, BodyItem, DocComment};
+
+use super::{body_to_string, code_block_to_fenced, link_to_reference, reference_link, INHERIT_DOC};
+
+/// Renders `doc` as a sequence of `///` rustdoc comment lines.
+///
+/// Block tags are translated to Markdown sections following the rustdoc conventions:
+/// `@param` tags are listed under `# Parameters` with their names in bold,
+/// `@return` and `@returns` go to `# Returns`, `@throws` and `@exception` to `# Errors`
+/// and `@panics` to `# Panics`. Any other block tag gets a section named after it.
+/// Tags sharing a section are grouped together in the order of their first appearance.
+///
+/// `{@link Foo}` inline tags become `[Foo]` intra-doc links,
+/// `{@code foo}` tags and code spans become `` `foo` ``.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse;
+/// use doctor::render::rustdoc::to_rustdoc_string;
+///
+/// let doc = parse(r#"/**
+///     * Returns the {@link Foo} instance.
+///     *
+///     * @param id the identifier
+///     * @returns the found instance
+///     */"#).unwrap();
+///
+/// assert_eq!(
+///     to_rustdoc_string(&doc),
+///     r#"/// Returns the [Foo] instance.
+/// ///
+/// /// # Parameters
+/// ///
+/// /// * **id** the identifier
+/// ///
+/// /// # Returns
+/// ///
+/// /// the found instance"#
+/// );
+/// ```
+#[must_use]
+pub fn to_rustdoc_string(doc: &DocComment<'_>) -> String {
+    let mut sections = vec![];
+    if let Some(description) = &doc.description {
+        sections.push(body_to_string(&description.body_items, item_to_rustdoc));
+    }
+
+    let mut tag_sections: Vec<(String, String)> = vec![];
+    for tag in &doc.block_tags {
+        let heading = section_heading(tag.name);
+        let content = block_tag_to_rustdoc(tag);
+        match tag_sections.iter_mut().find(|(h, _)| *h == heading) {
+            Some((_, section_content)) => section_content.push_str(&content),
+            None => tag_sections.push((heading, content)),
+        }
+    }
+    for (heading, content) in tag_sections {
+        sections.push(format!("# {heading}"));
+        sections.push(content);
+    }
+
+    let mut result = String::new();
+    for (index, section) in sections.iter().enumerate() {
+        if index > 0 {
+            result.push_str("///\n");
+        }
+        for line in section.trim_end().lines() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                result.push_str("///\n");
+            } else {
+                result.push_str("/// ");
+                result.push_str(line);
+                result.push('\n');
+            }
+        }
+    }
+    result.truncate(result.trim_end().len());
+    result
+}
+
+/// Returns the heading of the section the block tag named `name` belongs to.
+fn section_heading(name: &str) -> String {
+    match name {
+        "param" => "Parameters".to_owned(),
+        "return" | "returns" => "Returns".to_owned(),
+        "throws" | "exception" => "Errors".to_owned(),
+        "panics" => "Panics".to_owned(),
+        name => {
+            let mut chars = name.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+    }
+}
+
+fn block_tag_to_rustdoc(tag: &BlockTag<'_>) -> String {
+    let body = body_to_string(&tag.body_items, item_to_rustdoc);
+    let body = body.trim();
+    if tag.name != "param" {
+        return format!("{body}\n");
+    }
+
+    match body.split_once(char::is_whitespace) {
+        Some((name, description)) => format!("* **{}** {}\n", name, description.trim_start()),
+        None => format!("* **{body}**\n"),
+    }
+}
+
+fn item_to_rustdoc(item: &BodyItem<'_>) -> String {
+    match item {
+        BodyItem::InlineTag(tag) => match tag.name {
+            "link" | "linkplain" => link_to_reference(tag),
+            "code" => format!("`{}`", tag.body_lines.concat().trim()),
+            _ => tag.body_lines.concat(),
+        },
+        BodyItem::CodeSpan(code) => format!("`{}`", code.trim()),
+        BodyItem::CodeBlock { language, lines } => code_block_to_fenced(*language, lines),
+        BodyItem::Link { target, label } => reference_link(target, *label),
+        BodyItem::InheritDoc => INHERIT_DOC.to_owned(),
+        BodyItem::TextSegment(s) => (*s).to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ParseConfig;
+    use crate::{parse, parse_with_config};
+
+    #[test]
+    fn test_to_rustdoc_string() {
+        assert_eq!(to_rustdoc_string(&DocComment::default()), "");
+        assert_eq!(
+            to_rustdoc_string(
+                &parse(
+                    r"/**
+                    * Parses the {@code input}.
+                    *
+                    * See {@link Parser the parser} for details.
+                    *
+                    * @param input the text to parse
+                    * @param strict
+                    * @throws ParseError if the input is invalid
+                    * @panics when out of memory
+                    * @since 1.0
+                    * @returns the parsed value
+                    */"
+                )
+                .unwrap()
+            ),
+            "/// Parses the `input`.
+///
+/// See [the parser][Parser] for details.
+///
+/// # Parameters
+///
+/// * **input** the text to parse
+/// * **strict**
+///
+/// # Errors
+///
+/// ParseError if the input is invalid
+///
+/// # Panics
+///
+/// when out of memory
+///
+/// # Since
+///
+/// 1.0
+///
+/// # Returns
+///
+/// the parsed value"
+        );
+    }
+
+    #[test]
+    fn test_to_rustdoc_string_code_spans() {
+        assert_eq!(
+            to_rustdoc_string(
+                &parse_with_config(
+                    "/** Call {@code bar()}. */",
+                    ParseConfig {
+                        parse_code_spans: true,
+                        ..ParseConfig::default()
+                    }
+                )
+                .unwrap()
+            ),
+            "/// Call `bar()`."
+        );
+    }
+
+    #[test]
+    fn test_to_rustdoc_string_inherit_doc() {
+        assert_eq!(
+            to_rustdoc_string(
+                &parse_with_config(
+                    "/** {@inheritDoc} */",
+                    ParseConfig {
+                        parse_inherit_doc: true,
+                        ..ParseConfig::default()
+                    }
+                )
+                .unwrap()
+            ),
+            "/// {@inheritDoc}"
+        );
+    }
+
+    #[test]
+    fn test_to_rustdoc_string_lines() {
+        let rustdoc = to_rustdoc_string(
+            &parse("/**\n * Multi-line\n * description.\n *\n * @param foo the foo\n */").unwrap(),
+        );
+        assert!(rustdoc
+            .lines()
+            .all(|line| line == "///" || line.starts_with("/// ")));
+    }
+}
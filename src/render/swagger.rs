@@ -0,0 +1,112 @@
+//! Renderer for the CommonMark `description` fields of [OpenAPI](https://www.openapis.org/)
+//! (Swagger) documents.
+
+use crate::ast::{BodyItem, DocComment};
+
+use super::{body_to_string, code_block_to_fenced, INHERIT_DOC};
+
+/// Renders the description of `doc` as a CommonMark string,
+/// suitable for the `description` field of an OpenAPI document.
+///
+/// Block tags, including `@example`, are left out.
+/// `{@code foo}` inline tags and code spans become `` `foo` ``,
+/// `{@link target label}` tags and links become `[label](target)` Markdown links,
+/// any other inline tag is replaced with its body.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse;
+/// use doctor::render::swagger::to_swagger_description;
+///
+/// let doc = parse(r#"/**
+///     * Returns the {@code User} with the given id.
+///     * See {@link https://example.com/users the docs}.
+///     *
+///     * @param id the identifier
+///     * @example getUser(1)
+///     */"#).unwrap();
+///
+/// assert_eq!(
+///     to_swagger_description(&doc),
+///     "Returns the `User` with the given id.\nSee [the docs](https://example.com/users)."
+/// );
+/// ```
+#[must_use]
+pub fn to_swagger_description(doc: &DocComment<'_>) -> String {
+    doc.description
+        .as_ref()
+        .map_or_else(String::new, |description| {
+            body_to_string(&description.body_items, item_to_commonmark)
+                .lines()
+                .map(str::trim_end)
+                .collect::<Vec<_>>()
+                .join("\n")
+                .trim()
+                .to_owned()
+        })
+}
+
+/// Renders a Markdown inline link, i.e. `[label](target)`, using the target as the label if
+/// there's none.
+fn inline_link(target: &str, label: Option<&str>) -> String {
+    format!("[{}]({})", label.unwrap_or(target), target)
+}
+
+fn item_to_commonmark(item: &BodyItem<'_>) -> String {
+    match item {
+        BodyItem::InlineTag(tag) => {
+            let body = tag.body_lines.concat();
+            let body = body.trim();
+            match tag.name {
+                "code" => format!("`{body}`"),
+                "link" | "linkplain" => match body.split_once(char::is_whitespace) {
+                    Some((target, label)) => inline_link(target, Some(label.trim())),
+                    None => inline_link(body, None),
+                },
+                _ => body.to_owned(),
+            }
+        }
+        BodyItem::CodeSpan(code) => format!("`{}`", code.trim()),
+        BodyItem::CodeBlock { language, lines } => code_block_to_fenced(*language, lines),
+        BodyItem::Link { target, label } => inline_link(target, *label),
+        BodyItem::InheritDoc => INHERIT_DOC.to_owned(),
+        BodyItem::TextSegment(s) => (*s).to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ParseConfig;
+    use crate::{parse, parse_with_config};
+
+    #[test]
+    fn test_to_swagger_description() {
+        assert_eq!(to_swagger_description(&DocComment::default()), "");
+        assert_eq!(
+            to_swagger_description(&parse("/** @example foo() */").unwrap()),
+            ""
+        );
+        assert_eq!(
+            to_swagger_description(
+                &parse("/**\n * See {@link Foo} and {@linkplain Bar}.\n *\n * Second.\n */")
+                    .unwrap()
+            ),
+            "See [Foo](Foo) and [Bar](Bar).\n\nSecond."
+        );
+        assert_eq!(
+            to_swagger_description(
+                &parse_with_config(
+                    "/**\n * Example:\n * ```js\n * foo()\n * ```\n */",
+                    ParseConfig {
+                        parse_code_blocks: true,
+                        ..ParseConfig::default()
+                    }
+                )
+                .unwrap()
+            ),
+            "Example:\n```js\nfoo()\n```"
+        );
+    }
+}
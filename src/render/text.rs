@@ -0,0 +1,109 @@
+//! Renderer collapsing a doc comment into plain text.
+
+use crate::ast::DocComment;
+
+use super::plain_text;
+
+/// Renders `doc` as plain text.
+///
+/// The description is followed by a line for every block tag, formatted as `@name: body`.
+/// Inline tags are replaced with their body, code with its content and links with their label,
+/// if any, or target. Whitespace is collapsed, lines are trimmed and empty lines are dropped,
+/// and every block tag is kept on a single line.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse;
+/// use doctor::render::text::to_plain_text;
+///
+/// let doc = parse(r#"/**
+///     * Returns the   {@link Foo} instance.
+///     *
+///     * @param id the
+///     *   identifier
+///     * @returns the found instance
+///     */"#).unwrap();
+///
+/// assert_eq!(
+///     to_plain_text(&doc),
+///     "Returns the Foo instance.\n@param: id the identifier\n@returns: the found instance"
+/// );
+/// ```
+#[must_use]
+pub fn to_plain_text(doc: &DocComment<'_>) -> String {
+    let description = doc
+        .description
+        .as_ref()
+        .map(|description| plain_text(&description.body_items))
+        .unwrap_or_default();
+    let description = description.lines().map(collapse_whitespace);
+    let block_tags = doc.block_tags.iter().map(|tag| {
+        let body = collapse_whitespace(&plain_text(&tag.body_items));
+        if body.is_empty() {
+            format!("@{}", tag.name)
+        } else {
+            format!("@{}: {}", tag.name, body)
+        }
+    });
+
+    description
+        .chain(block_tags)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl DocComment<'_> {
+    /// Renders the comment as plain text.
+    ///
+    /// See [`to_plain_text`] for details.
+    #[must_use]
+    pub fn to_plain_text(&self) -> String {
+        to_plain_text(self)
+    }
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ParseConfig;
+    use crate::{parse, parse_with_config};
+
+    #[test]
+    fn test_to_plain_text() {
+        assert_eq!(
+            to_plain_text(
+                &parse(
+                    r"/**
+                    * First  line,
+                    *
+                    * second {@code line}.
+                    * @deprecated
+                    * @since 1.0
+                    */"
+                )
+                .unwrap()
+            ),
+            "First line,\nsecond line.\n@deprecated\n@since: 1.0"
+        );
+        assert_eq!(
+            to_plain_text(
+                &parse_with_config(
+                    "/** See {@link Foo the foo}. */",
+                    ParseConfig {
+                        parse_links: true,
+                        ..ParseConfig::default()
+                    }
+                )
+                .unwrap()
+            ),
+            "See the foo."
+        );
+        assert_eq!(parse("/** */").unwrap().to_plain_text(), "");
+    }
+}
@@ -0,0 +1,175 @@
+//! Renderer for [TSDoc](https://tsdoc.org/), the doc comment standard for TypeScript.
+
+use crate::ast::{BlockTag, BodyItem, DocComment, InlineTag};
+
+use super::{
+    body_to_string, code_block_to_fenced, inline_tag_to_string, to_block_comment, INHERIT_DOC,
+};
+
+/// Renders `doc` as a TSDoc comment.
+///
+/// `@return` becomes `@returns`, `@param` bodies are written as `name - description`,
+/// keeping a leading `{Type}` annotation, links are written as `{@link Target | label}`
+/// and code spans as `` `code` ``. Other tags are kept as they are.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::parse;
+/// use doctor::render::tsdoc::to_tsdoc_string;
+///
+/// let doc = parse(r#"/**
+///     * Returns the {@link Foo the foo}.
+///     *
+///     * @param id the identifier
+///     * @return the found instance
+///     */"#).unwrap();
+///
+/// assert_eq!(
+///     to_tsdoc_string(&doc),
+///     r#"/**
+///  * Returns the {@link Foo | the foo}.
+///  *
+///  * @param id - the identifier
+///  * @returns the found instance
+///  */"#
+/// );
+/// ```
+#[must_use]
+pub fn to_tsdoc_string(doc: &DocComment<'_>) -> String {
+    let description = doc
+        .description
+        .as_ref()
+        .map(|description| body_to_string(&description.body_items, item_to_tsdoc));
+    let block_tags = doc
+        .block_tags
+        .iter()
+        .map(block_tag_to_tsdoc)
+        .collect::<String>();
+
+    let sections = description
+        .into_iter()
+        .chain(Some(block_tags).filter(|block_tags| !block_tags.is_empty()));
+    to_block_comment(sections)
+}
+
+fn block_tag_to_tsdoc(tag: &BlockTag<'_>) -> String {
+    let name = match tag.name {
+        "return" => "returns",
+        name => name,
+    };
+    let body = body_to_string(&tag.body_items, item_to_tsdoc);
+    let body = match name {
+        "param" => param_body(body.trim()),
+        _ => body.trim_end().to_owned(),
+    };
+    if body.is_empty() {
+        format!("@{name}\n")
+    } else {
+        format!("@{name} {body}\n")
+    }
+}
+
+/// Rewrites a `@param` body as `{Type} name - description`,
+/// the type annotation and the description being optional.
+fn param_body(body: &str) -> String {
+    let (param_type, rest) = match body.strip_prefix('{').and_then(|s| s.split_once('}')) {
+        Some((param_type, rest)) => (Some(param_type.trim()), rest.trim_start()),
+        None => (None, body),
+    };
+    let (name, description) = rest.split_at(rest.find(char::is_whitespace).unwrap_or(rest.len()));
+    let description = description.trim_start();
+    let description = description
+        .strip_prefix('-')
+        .map_or(description, str::trim_start);
+
+    let mut result = param_type.map_or_else(String::new, |param_type| format!("{{{param_type}}} "));
+    result.push_str(name);
+    if !description.is_empty() {
+        result.push_str(" - ");
+        result.push_str(description);
+    }
+    result.trim_end().to_owned()
+}
+
+/// Renders a `{@link reference label}` inline tag with the TSDoc `|` label separator.
+fn link_to_tsdoc(tag: &InlineTag<'_>) -> String {
+    let body = tag.body_lines.concat();
+    let body = body.trim();
+    match body.split_once(char::is_whitespace) {
+        Some((target, label)) if !body.contains('|') => link(target, Some(label.trim())),
+        _ => inline_tag_to_string(tag),
+    }
+}
+
+/// Renders a `{@link Target}` or `{@link Target | label}` inline tag.
+fn link(target: &str, label: Option<&str>) -> String {
+    label.map_or_else(
+        || format!("{{@link {target}}}"),
+        |label| format!("{{@link {target} | {label}}}"),
+    )
+}
+
+fn item_to_tsdoc(item: &BodyItem<'_>) -> String {
+    match item {
+        BodyItem::InlineTag(tag) if tag.name == "link" => link_to_tsdoc(tag),
+        BodyItem::InlineTag(tag) => inline_tag_to_string(tag),
+        BodyItem::CodeSpan(code) => format!("`{code}`"),
+        BodyItem::CodeBlock { language, lines } => code_block_to_fenced(*language, lines),
+        BodyItem::Link { target, label } => link(target, *label),
+        BodyItem::InheritDoc => INHERIT_DOC.to_owned(),
+        BodyItem::TextSegment(s) => (*s).to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use crate::style::tsdoc::parse_tsdoc;
+
+    #[test]
+    fn test_to_tsdoc_string() {
+        assert_eq!(to_tsdoc_string(&DocComment::default()), "/**\n */");
+        assert_eq!(
+            to_tsdoc_string(
+                &parse(
+                    "/**\n * @param {string} id - the id\n * @param name the name\n * @param flag\n */"
+                )
+                .unwrap()
+            ),
+            "/**\n * @param {string} id - the id\n * @param name - the name\n * @param flag\n */"
+        );
+
+        let doc = parse_tsdoc(
+            r"/**
+            * See {@link Foo the foo}, {@link Bar | bar} and {@link Baz}.
+            *
+            * @param id the {@code id}
+            * @return the result
+            * @_internal
+            */",
+        )
+        .unwrap();
+        let tsdoc = to_tsdoc_string(&doc);
+        assert_eq!(
+            tsdoc,
+            "/**
+ * See {@link Foo | the foo}, {@link Bar | bar} and {@link Baz}.
+ *
+ * @param id - the {@code id}
+ * @returns the result
+ * @_internal
+ */"
+        );
+
+        // Parsing the output gives the input back, with only the TSDoc conventions applied.
+        let mut canonicalized_doc = doc.clone();
+        canonicalized_doc.block_tags[0].body_items[0] = BodyItem::TextSegment("id - the ");
+        canonicalized_doc.block_tags[1].name = "returns";
+        assert_eq!(parse_tsdoc(&tsdoc), Ok(canonicalized_doc));
+
+        // Rendering the TSDoc parse of the output gives the output back.
+        assert_eq!(to_tsdoc_string(&parse_tsdoc(&tsdoc).unwrap()), tsdoc);
+    }
+}
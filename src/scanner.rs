@@ -0,0 +1,171 @@
+/// State of the doc comment scanner.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum State {
+    Code,
+    /// A string delimited by the `quote` character, i.e. `"`, `'` or `` ` ``.
+    String {
+        quote: u8,
+    },
+    StringEscape {
+        quote: u8,
+    },
+    /// A raw string literal, e.g. `r#"…"#`, closed by a `"` followed by `hashes` `#` characters.
+    RawString {
+        hashes: usize,
+    },
+    LineComment,
+    BlockComment,
+    DocComment {
+        start: usize,
+    },
+}
+
+/// Scans `input` for doc comments and returns their byte offsets along with their text,
+/// including the `/**` and `*/` delimiters.
+///
+/// String literals, i.e. double-quoted, single-quoted, backtick template literals and raw
+/// strings such as `r#"…"#`, as well as regular `//` and `/*` comments are skipped,
+/// so that `/**` sequences inside of them aren't mistaken for doc comments.
+/// Single-quoted literals end at the end of the line, so that an unpaired `'`,
+/// e.g. of a Rust lifetime, doesn't hide the rest of `input`.
+/// An unterminated doc comment spans until the end of `input`.
+pub fn doc_comments(input: &str) -> Vec<(usize, &str)> {
+    let bytes = input.as_bytes();
+    let mut comments = vec![];
+    let mut state = State::Code;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        state = match state {
+            State::Code if rest.starts_with(b"/**") && !rest.starts_with(b"/**/") => {
+                let start = i;
+                i += 3;
+                State::DocComment { start }
+            }
+            State::Code if rest.starts_with(b"/*") => {
+                i += 2;
+                State::BlockComment
+            }
+            State::Code if rest.starts_with(b"//") => {
+                i += 2;
+                State::LineComment
+            }
+            State::Code if b"\"'`".contains(&rest[0]) => {
+                i += 1;
+                State::String { quote: rest[0] }
+            }
+            State::Code if rest[0] == b'r' && !is_identifier_end(&bytes[..i]) => {
+                let hashes = rest[1..].iter().take_while(|&&b| b == b'#').count();
+                i += 1;
+                if rest.get(1 + hashes) == Some(&b'"') {
+                    i += hashes + 1;
+                    State::RawString { hashes }
+                } else {
+                    State::Code
+                }
+            }
+            State::String { quote } if rest[0] == b'\\' => {
+                i += 1;
+                State::StringEscape { quote }
+            }
+            State::String { quote } if rest[0] == quote => {
+                i += 1;
+                State::Code
+            }
+            State::String { quote: b'\'' } | State::LineComment if rest[0] == b'\n' => {
+                i += 1;
+                State::Code
+            }
+            State::StringEscape { quote } => {
+                i += 1;
+                State::String { quote }
+            }
+            State::RawString { hashes }
+                if rest[0] == b'"'
+                    && rest
+                        .get(1..=hashes)
+                        .is_some_and(|closing| closing.iter().all(|&b| b == b'#')) =>
+            {
+                i += 1 + hashes;
+                State::Code
+            }
+            State::BlockComment if rest.starts_with(b"*/") => {
+                i += 2;
+                State::Code
+            }
+            State::DocComment { start } if rest.starts_with(b"*/") => {
+                i += 2;
+                comments.push((start, &input[start..i]));
+                State::Code
+            }
+            state => {
+                i += 1;
+                state
+            }
+        };
+    }
+
+    if let State::DocComment { start } = state {
+        comments.push((start, &input[start..]));
+    }
+
+    comments
+}
+
+/// Returns `true` if `code` ends with an identifier character, other than the `b` prefix
+/// of a byte string, e.g. `br"…"`.
+fn is_identifier_end(code: &[u8]) -> bool {
+    let is_identifier_char = |b: &u8| b.is_ascii_alphanumeric() || *b == b'_';
+    match code {
+        [.., before, b'b'] => is_identifier_char(before),
+        [.., last] => is_identifier_char(last),
+        [] => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_doc_comments() {
+        assert_eq!(doc_comments(""), vec![]);
+        assert_eq!(
+            doc_comments("/** First */\nfn foo() {}\n\n  /**\n   * Second\n   */\nfn bar() {}"),
+            vec![(0, "/** First */"), (28, "/**\n   * Second\n   */")]
+        );
+        assert_eq!(
+            doc_comments(r#"let s = "/** not a comment \" */"; /** Comment */"#),
+            vec![(35, "/** Comment */")]
+        );
+        assert_eq!(
+            doc_comments("// /** not a comment */\n/* /** not a comment */ /**/ /** Comment */"),
+            vec![(53, "/** Comment */")]
+        );
+        assert_eq!(
+            doc_comments("let c = '\"'; /** Doc A. */ let d = '\\''; /** Doc B. */"),
+            vec![(13, "/** Doc A. */"), (41, "/** Doc B. */")]
+        );
+        assert_eq!(
+            doc_comments("fn f<'a>(s: &'a str) {}\n/** Doc. */"),
+            vec![(24, "/** Doc. */")]
+        );
+        assert_eq!(
+            doc_comments("const s = `/* not a comment\n ${x}`; /** Doc. */"),
+            vec![(36, "/** Doc. */")]
+        );
+        assert_eq!(
+            doc_comments(r##"let s = r"a\"; let t = br#"/* " */"#; /** Doc. */"##),
+            vec![(38, "/** Doc. */")]
+        );
+        assert_eq!(
+            doc_comments("let bar = 1; /** Doc. */"),
+            vec![(13, "/** Doc. */")]
+        );
+        assert_eq!(
+            doc_comments("/** Unterminated"),
+            vec![(0, "/** Unterminated")]
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Doc comment AST annotated with the location of its nodes in the parsed input.
+//!
+//! All ranges are byte ranges relative to the start of the parsed comment.
+
+use std::ops::Range;
+
+use crate::ast::{BlockTag, BodyItem, Description, DocComment, InlineTag};
+
+/// Doc comment with the location of its tags in the input.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct SpannedDocComment<'a> {
+    /// Description of the doc comment.
+    pub description: Option<Description<'a>>,
+    /// Inline tags found in the description, in document order.
+    pub description_inline_tags: Vec<SpannedInlineTag<'a>>,
+    /// Block tags of the doc comment.
+    pub block_tags: Vec<SpannedBlockTag<'a>>,
+}
+
+/// Block tag with the location of its name in the input.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SpannedBlockTag<'a> {
+    /// The tag itself.
+    pub tag: BlockTag<'a>,
+    /// Byte range of the tag name, not including the leading `@`.
+    pub name_range: Range<usize>,
+    /// Inline tags found in the tag body, in document order.
+    pub inline_tags: Vec<SpannedInlineTag<'a>>,
+}
+
+/// Inline tag with the location of its name and braces in the input.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SpannedInlineTag<'a> {
+    /// The tag itself.
+    pub tag: InlineTag<'a>,
+    /// Byte range of the tag name, not including the leading `@`.
+    pub name_range: Range<usize>,
+    /// Byte offset of the opening `{`.
+    pub open_brace: usize,
+    /// Byte offset of the closing `}`.
+    pub close_brace: usize,
+}
+
+impl<'a> SpannedDocComment<'a> {
+    /// Annotates `doc` with the location of its nodes in `input`.
+    ///
+    /// `doc` must have been parsed from `input`, as the locations are computed from the
+    /// positions of the slices the AST borrows from it.
+    pub(crate) fn new(input: &'a str, doc: DocComment<'a>) -> Self {
+        let description_inline_tags = doc
+            .description
+            .as_ref()
+            .map(|description| spanned_inline_tags(input, &description.body_items))
+            .unwrap_or_default();
+        let block_tags = doc
+            .block_tags
+            .into_iter()
+            .map(|tag| SpannedBlockTag {
+                name_range: range_in(input, tag.name),
+                inline_tags: spanned_inline_tags(input, &tag.body_items),
+                tag,
+            })
+            .collect();
+
+        Self {
+            description: doc.description,
+            description_inline_tags,
+            block_tags,
+        }
+    }
+}
+
+fn spanned_inline_tags<'a>(
+    input: &'a str,
+    body_items: &[BodyItem<'a>],
+) -> Vec<SpannedInlineTag<'a>> {
+    body_items
+        .iter()
+        .filter_map(|item| match item {
+            BodyItem::InlineTag(tag) => {
+                let name_range = range_in(input, tag.name);
+                let body_end = tag
+                    .body_lines
+                    .last()
+                    .map_or(name_range.end, |line| range_in(input, line).end);
+                // The name is preceded by a one byte prefix and the body is only followed
+                // by whitespace and line leading before the closing brace.
+                let open_brace = input[..name_range.start].rfind('{').unwrap_or(0);
+                let close_brace = input[body_end..]
+                    .find('}')
+                    .map_or(input.len(), |offset| body_end + offset);
+
+                Some(SpannedInlineTag {
+                    tag: tag.clone(),
+                    name_range,
+                    open_brace,
+                    close_brace,
+                })
+            }
+            BodyItem::TextSegment(_)
+            | BodyItem::CodeSpan(_)
+            | BodyItem::CodeBlock { .. }
+            | BodyItem::Link { .. }
+            | BodyItem::InheritDoc => None,
+        })
+        .collect()
+}
+
+/// Returns the byte range `slice` occupies in `input`.
+fn range_in(input: &str, slice: &str) -> Range<usize> {
+    let start = slice.as_ptr() as usize - input.as_ptr() as usize;
+    debug_assert!(start + slice.len() <= input.len());
+    start..start + slice.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_spanned;
+
+    #[test]
+    fn test_name_range() {
+        let input = "/**\n * See {@link Foo}.\n *\n * @param foo the {@code foo}\n * @returns\n */";
+        let doc = parse_spanned(input).unwrap();
+
+        assert_eq!(doc.description_inline_tags.len(), 1);
+        assert_eq!(doc.description_inline_tags[0].name_range, 13..17);
+        assert_eq!(&input[13..17], "link");
+
+        let ranges: Vec<_> = doc
+            .block_tags
+            .iter()
+            .map(|tag| &input[tag.name_range.clone()])
+            .collect();
+        assert_eq!(ranges, vec!["param", "returns"]);
+        assert_eq!(doc.block_tags[0].inline_tags.len(), 1);
+        assert_eq!(
+            &input[doc.block_tags[0].inline_tags[0].name_range.clone()],
+            "code"
+        );
+
+        let tag = &doc.description_inline_tags[0];
+        assert_eq!(&input[tag.open_brace..=tag.close_brace], "{@link Foo}");
+        let tag = &doc.block_tags[0].inline_tags[0];
+        assert_eq!(&input[tag.open_brace..=tag.close_brace], "{@code foo}");
+
+        for tag in &doc.block_tags {
+            assert_eq!(
+                tag.name_range.end,
+                tag.name_range.start + tag.tag.name.len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_brace_offsets() {
+        let input = "/**\n * {@link Foo\n * Bar } and {@inheritDoc}\n */";
+        let doc = parse_spanned(input).unwrap();
+
+        let ranges: Vec<_> = doc
+            .description_inline_tags
+            .iter()
+            .map(|tag| &input[tag.open_brace..=tag.close_brace])
+            .collect();
+        assert_eq!(ranges, vec!["{@link Foo\n * Bar }", "{@inheritDoc}"]);
+    }
+}
@@ -0,0 +1,107 @@
+//! Support for [Doxygen](https://www.doxygen.nl/) doc comments, e.g. in C and C++ code.
+
+use crate::ast::DocComment;
+use crate::config::ParseConfig;
+use crate::error::Error;
+use crate::parse_with_config;
+
+/// Parses `input` as a Doxygen comment.
+///
+/// The following deviations from the base grammar are applied:
+///
+/// - the comment may also start with `/*!`, see [`ParseConfig::allow_bang_comment_start`];
+/// - block tags may also be written as `\name`, e.g. `\param` or `\brief`,
+///   see [`ParseConfig::allow_backslash_tags`]. Both syntaxes produce tags with the same name.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::style::doxygen::parse_doxygen;
+///
+/// let doc = parse_doxygen("/*!\n * \\brief Adds two numbers.\n * @param a the first one\n */").unwrap();
+///
+/// assert_eq!(doc.block_tags[0].name, "brief");
+/// assert_eq!(doc.block_tags[1].name, "param");
+/// ```
+///
+/// # Errors
+///
+/// If `input` is not a valid doc comment, an error explaining where the parsing failed is returned.
+pub fn parse_doxygen(input: &str) -> Result<DocComment<'_>, Error> {
+    parse_with_config(input, doxygen_config())
+}
+
+/// Returns the [`ParseConfig`] used by [`parse_doxygen`].
+#[must_use]
+pub fn doxygen_config() -> ParseConfig {
+    ParseConfig {
+        allow_bang_comment_start: true,
+        allow_backslash_tags: true,
+        ..ParseConfig::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{BlockTag, BodyItem, Description};
+
+    #[test]
+    fn test_parse_doxygen() {
+        let expected = DocComment {
+            description: Some(Description {
+                body_items: vec![
+                    BodyItem::TextSegment("Escaped \\{ brace.\n"),
+                    BodyItem::TextSegment("\n"),
+                ],
+            }),
+            block_tags: vec![
+                BlockTag {
+                    name: "brief",
+                    position: 0,
+                    body_items: vec![BodyItem::TextSegment("Adds numbers.\n")],
+                },
+                BlockTag {
+                    name: "param",
+                    position: 1,
+                    body_items: vec![BodyItem::TextSegment("a the first one\n")],
+                },
+                BlockTag {
+                    name: "returns",
+                    position: 2,
+                    body_items: vec![BodyItem::TextSegment("the sum\n")],
+                },
+            ],
+        };
+
+        assert_eq!(
+            parse_doxygen(
+                r"/*!
+                * Escaped \{ brace.
+                *
+                * \brief Adds numbers.
+                * \param a the first one
+                * @returns the sum
+                */"
+            ),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            parse_doxygen(
+                r"/**
+                * Escaped \{ brace.
+                *
+                * @brief Adds numbers.
+                * @param a the first one
+                * \returns the sum
+                */"
+            ),
+            Ok(expected)
+        );
+        assert!(crate::parse("/*! Description. */").is_err());
+        assert!(crate::parse("/** \\param a */")
+            .unwrap()
+            .block_tags
+            .is_empty());
+    }
+}
@@ -0,0 +1,168 @@
+//! Support for doc comments following the
+//! [Google Java Style Guide](https://google.github.io/styleguide/javaguide.html#s7-javadoc).
+
+use crate::ast::{BlockTag, Description};
+use crate::error::Error;
+use crate::parse;
+use crate::render::{plain_text, split_first_word};
+
+/// A doc comment with its `@param`, `@throws` and `@return` tags extracted into structured form.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct GoogleDocComment<'a> {
+    pub description: Option<Description<'a>>,
+    pub params: Vec<ParamDoc<'a>>,
+    pub throws: Vec<ThrowsDoc<'a>>,
+    pub returns: Option<String>,
+    /// Block tags other than `@param`, `@throws` and `@return`.
+    pub other_tags: Vec<BlockTag<'a>>,
+}
+
+/// A `@param name description` tag.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ParamDoc<'a> {
+    pub name: &'a str,
+    pub description: String,
+}
+
+/// A `@throws ExceptionType description` tag.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ThrowsDoc<'a> {
+    pub exception_type: &'a str,
+    pub description: String,
+}
+
+/// Parses `input` into a [`GoogleDocComment`].
+///
+/// The text of inline tags within the extracted descriptions is kept as is.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::style::google::{parse_google_style, ParamDoc, ThrowsDoc};
+///
+/// let doc = parse_google_style(r#"/**
+///     * Returns the user with the given id.
+///     *
+///     * @param id the user's identifier
+///     * @return the found user
+///     * @throws NotFoundException if there is no such user
+///     */"#).unwrap();
+///
+/// assert_eq!(doc.params, vec![ParamDoc { name: "id", description: "the user's identifier".to_owned() }]);
+/// assert_eq!(doc.returns.as_deref(), Some("the found user"));
+/// assert_eq!(
+///     doc.throws,
+///     vec![ThrowsDoc {
+///         exception_type: "NotFoundException",
+///         description: "if there is no such user".to_owned(),
+///     }]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// If `input` is not a valid doc comment, an error explaining where the parsing failed is returned.
+pub fn parse_google_style(input: &str) -> Result<GoogleDocComment<'_>, Error> {
+    let doc = parse(input)?;
+
+    let mut google_doc = GoogleDocComment {
+        description: doc.description,
+        ..GoogleDocComment::default()
+    };
+    for tag in doc.block_tags {
+        match tag.name {
+            "param" => {
+                let (name, description) = split_first_word(&tag.body_items);
+                google_doc.params.push(ParamDoc { name, description });
+            }
+            "throws" | "exception" => {
+                let (exception_type, description) = split_first_word(&tag.body_items);
+                google_doc.throws.push(ThrowsDoc {
+                    exception_type,
+                    description,
+                });
+            }
+            "return" | "returns" => {
+                google_doc.returns = Some(plain_text(&tag.body_items));
+            }
+            _ => google_doc.other_tags.push(tag),
+        }
+    }
+
+    Ok(google_doc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::BodyItem;
+
+    #[test]
+    fn test_parse_google_style() {
+        assert_eq!(
+            parse_google_style(
+                r"/**
+                * Description.
+                *
+                * @param first the first {@code int}
+                *     spanning multiple lines
+                * @param second
+                * @throws IllegalArgumentException
+                * @exception IOException if the {@link Reader} fails
+                * @returns the result
+                * @since 1.0
+                */"
+            ),
+            Ok(GoogleDocComment {
+                description: Some(Description {
+                    body_items: vec![
+                        BodyItem::TextSegment("Description.\n"),
+                        BodyItem::TextSegment("\n"),
+                    ]
+                }),
+                params: vec![
+                    ParamDoc {
+                        name: "first",
+                        description: "the first int\nspanning multiple lines".to_owned(),
+                    },
+                    ParamDoc {
+                        name: "second",
+                        description: String::new(),
+                    },
+                ],
+                throws: vec![
+                    ThrowsDoc {
+                        exception_type: "IllegalArgumentException",
+                        description: String::new(),
+                    },
+                    ThrowsDoc {
+                        exception_type: "IOException",
+                        description: "if the Reader fails".to_owned(),
+                    },
+                ],
+                returns: Some("the result".to_owned()),
+                other_tags: vec![BlockTag {
+                    name: "since",
+                    position: 5,
+                    body_items: vec![BodyItem::TextSegment("1.0\n")],
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_google_style_param_starting_with_inline_tag() {
+        assert_eq!(
+            parse_google_style("/** @param {@code foo} the foo */").map(|doc| doc.params),
+            Ok(vec![ParamDoc {
+                name: "",
+                description: "foo the foo".to_owned(),
+            }])
+        );
+    }
+
+    #[test]
+    fn test_parse_google_style_invalid() {
+        assert!(parse_google_style("/** {@} */").is_err());
+    }
+}
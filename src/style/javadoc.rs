@@ -0,0 +1,139 @@
+//! Support for classic [JavaDoc](https://docs.oracle.com/en/java/javase/17/docs/specs/javadoc/doc-comment-spec.html) comments.
+
+use crate::ast::{BodyItem, DocComment};
+use crate::config::{ParseConfig, TagNameSyntax};
+use crate::error::Error;
+use crate::parse_with_config;
+
+/// Names of the standard JavaDoc inline tags.
+const STANDARD_INLINE_TAGS: [&str; 6] =
+    ["code", "link", "value", "literal", "inheritDoc", "docRoot"];
+
+/// Parses `input` as a classic JavaDoc comment.
+///
+/// Tag names may only consist of ASCII letters, see [`TagNameSyntax::Alphabetic`],
+/// single-line `{@code}` tags become [`BodyItem::CodeSpan`] items
+/// and single-line `{@link}` tags become [`BodyItem::Link`] items.
+/// HTML in descriptions is kept as text.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::ast::BodyItem;
+/// use doctor::style::javadoc::parse_javadoc;
+///
+/// let doc = parse_javadoc("/** Returns {@code null} if there's no {@link Foo}. */").unwrap();
+///
+/// assert_eq!(doc.description.unwrap().body_items[1], BodyItem::CodeSpan("null"));
+/// ```
+///
+/// # Errors
+///
+/// If `input` is not a valid doc comment, an error explaining where the parsing failed is returned.
+pub fn parse_javadoc(input: &str) -> Result<DocComment<'_>, Error> {
+    parse_with_config(input, javadoc_config())
+}
+
+/// Returns the [`ParseConfig`] used by [`parse_javadoc`].
+#[must_use]
+pub fn javadoc_config() -> ParseConfig {
+    ParseConfig {
+        tag_name_syntax: TagNameSyntax::Alphabetic,
+        parse_code_spans: true,
+        parse_links: true,
+        ..ParseConfig::default()
+    }
+}
+
+/// A doc comment parsed by [`parse_javadoc`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct JavaDocComment<'a> {
+    pub doc: DocComment<'a>,
+}
+
+impl<'a> From<DocComment<'a>> for JavaDocComment<'a> {
+    fn from(doc: DocComment<'a>) -> Self {
+        Self { doc }
+    }
+}
+
+impl<'a> JavaDocComment<'a> {
+    /// Returns an iterator over the standard JavaDoc inline tags of the comment in document order:
+    /// `{@code}`, `{@link}`, `{@value}`, `{@literal}`, `{@inheritDoc}` and `{@docRoot}`.
+    ///
+    /// Code spans and links are included, as [`parse_javadoc`] turns single-line
+    /// `{@code}` and `{@link}` tags into them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doctor::style::javadoc::{parse_javadoc, JavaDocComment};
+    ///
+    /// let doc = JavaDocComment::from(
+    ///     parse_javadoc("/** {@inheritDoc} {@custom tag}\n * @return {@value #MAX} */").unwrap(),
+    /// );
+    ///
+    /// assert_eq!(doc.javadoc_inline_tags().count(), 2);
+    /// ```
+    pub fn javadoc_inline_tags(&self) -> impl Iterator<Item = &BodyItem<'a>> {
+        self.doc
+            .description
+            .iter()
+            .flat_map(|description| description.body_items.iter())
+            .chain(
+                self.doc
+                    .block_tags
+                    .iter()
+                    .flat_map(|tag| tag.body_items.iter()),
+            )
+            .filter(|item| match item {
+                BodyItem::InlineTag(tag) => STANDARD_INLINE_TAGS.contains(&tag.name),
+                BodyItem::CodeSpan(_) | BodyItem::Link { .. } | BodyItem::InheritDoc => true,
+                BodyItem::TextSegment(_) | BodyItem::CodeBlock { .. } => false,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::InlineTag;
+
+    #[test]
+    fn test_parse_javadoc() {
+        let doc = parse_javadoc(
+            r"/**
+            * <p>Returns {@code x} or {@literal <y>}, see {@link Foo the foo}.
+            * {@custom tag} {@docRoot}
+            *
+            * @param x the {@code
+            *     x}
+            * @since 1.0
+            */",
+        )
+        .unwrap();
+        let doc = JavaDocComment::from(doc);
+
+        assert_eq!(
+            doc.javadoc_inline_tags().collect::<Vec<_>>(),
+            vec![
+                &BodyItem::CodeSpan("x"),
+                &BodyItem::InlineTag(InlineTag::new("literal", "<y>")),
+                &BodyItem::Link {
+                    target: "Foo",
+                    label: Some("the foo")
+                },
+                &BodyItem::InlineTag(InlineTag::new("docRoot", "")),
+                &BodyItem::InlineTag(InlineTag {
+                    name: "code",
+                    body_lines: vec!["\n", "x"]
+                }),
+            ]
+        );
+        assert_eq!(
+            parse_javadoc("/** @since1 */").unwrap().block_tags[0].name,
+            "since"
+        );
+        assert!(parse_javadoc("/** @_internal */").is_err());
+    }
+}
@@ -0,0 +1,138 @@
+//! Parsers for specific doc comment styles built on top of [`parse`](crate::parse).
+
+pub mod doxygen;
+pub mod google;
+pub mod javadoc;
+pub mod python;
+pub mod tsdoc;
+
+use crate::ast::DocComment;
+use crate::config::ParseConfig;
+use crate::error::Error;
+use crate::parse_with_config;
+
+/// Doc comment dialect selecting the parser used by [`parse_multi_language`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Dialect {
+    /// Chosen from the comment opener: `/*!` is parsed as Doxygen,
+    /// `"""` and `'''` as a Python docstring and anything else as TSDoc,
+    /// the most permissive dialect about tag names.
+    ///
+    /// `///` line comments are rejected, as none of the parsers supports them.
+    #[default]
+    Auto,
+    /// See [`javadoc::parse_javadoc`].
+    JavaDoc,
+    /// See [`tsdoc::parse_tsdoc`].
+    TSDoc,
+    /// See [`doxygen::parse_doxygen`].
+    Doxygen,
+    /// A JavaDoc-like comment with KDoc `[Foo]` reference links,
+    /// see [`ParseConfig::parse_kdoc_links`].
+    KDoc,
+    /// See [`python::parse_python_docstring`].
+    Python,
+}
+
+/// Parses `input` with the parser of `dialect`, see [`Dialect::Auto`] for the detection rules.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::style::{parse_multi_language, Dialect};
+///
+/// let doxygen = parse_multi_language("/*! \\brief Summary */", Dialect::Auto).unwrap();
+/// assert_eq!(doxygen.block_tags[0].name, "brief");
+///
+/// let python = parse_multi_language("\"\"\"Summary.\n\nArgs:\n    x: The x.\n\"\"\"", Dialect::Auto)
+///     .unwrap();
+/// assert_eq!(python.param_names(), vec!["x"]);
+///
+/// let tsdoc = parse_multi_language("/** @_internal */", Dialect::Auto).unwrap();
+/// assert_eq!(tsdoc.block_tags[0].name, "_internal");
+///
+/// assert!(parse_multi_language("/// Summary", Dialect::Auto).is_err());
+/// ```
+///
+/// # Errors
+///
+/// If `input` is not a valid doc comment of the dialect, an error explaining where the parsing
+/// failed is returned.
+/// With [`Dialect::Auto`], an error is also returned for `///` line comments.
+pub fn parse_multi_language(input: &str, dialect: Dialect) -> Result<DocComment<'_>, Error> {
+    match dialect {
+        Dialect::Auto => parse_multi_language(input, detect_dialect(input)?),
+        Dialect::JavaDoc => javadoc::parse_javadoc(input),
+        Dialect::TSDoc => tsdoc::parse_tsdoc(input),
+        Dialect::Doxygen => doxygen::parse_doxygen(input),
+        Dialect::KDoc => parse_with_config(
+            input,
+            ParseConfig {
+                parse_kdoc_links: true,
+                ..ParseConfig::default()
+            },
+        ),
+        Dialect::Python => python::parse_python_docstring(input),
+    }
+}
+
+/// Picks the dialect of `input` from its opener.
+fn detect_dialect(input: &str) -> Result<Dialect, Error> {
+    let input = input.trim_start();
+    if input.starts_with("///") {
+        Err(Error::ParseError(
+            "`///` line comments are not supported, expected a `/**` or `/*!` block comment"
+                .to_owned(),
+        ))
+    } else if input.starts_with("/*!") {
+        Ok(Dialect::Doxygen)
+    } else if input.starts_with("\"\"\"") || input.starts_with("'''") {
+        Ok(Dialect::Python)
+    } else {
+        Ok(Dialect::TSDoc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_multi_language() {
+        let input = "/** See [Foo]. */";
+        assert_eq!(
+            parse_multi_language(input, Dialect::Auto),
+            tsdoc::parse_tsdoc(input)
+        );
+        assert_ne!(
+            parse_multi_language(input, Dialect::KDoc),
+            parse_multi_language(input, Dialect::JavaDoc)
+        );
+
+        let input = "/*!\n * \\param foo the foo\n */";
+        assert_eq!(
+            parse_multi_language(input, Dialect::Auto),
+            doxygen::parse_doxygen(input)
+        );
+        assert!(parse_multi_language(input, Dialect::JavaDoc).is_err());
+
+        let input = "/**\n * @_internal\n * @my-tag foo\n */";
+        assert!(parse_multi_language(input, Dialect::JavaDoc).is_err());
+        assert_eq!(
+            parse_multi_language(input, Dialect::Auto)
+                .unwrap()
+                .tag_names()
+                .collect::<Vec<_>>(),
+            vec!["_internal", "my-tag"]
+        );
+
+        assert!(parse_multi_language("  /// Summary.", Dialect::Auto).is_err());
+
+        let input = "  '''Summary.'''";
+        assert_eq!(
+            parse_multi_language(input, Dialect::Auto),
+            python::parse_python_docstring(input)
+        );
+    }
+}
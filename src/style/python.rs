@@ -0,0 +1,410 @@
+//! Support for Python docstrings in the
+//! [Google](https://google.github.io/styleguide/pyguide.html#38-comments-and-docstrings)
+//! and [NumPy](https://numpydoc.readthedocs.io/en/latest/format.html) styles.
+
+use crate::ast::{BlockTag, BodyItem, Description, DocComment};
+use crate::error::Error;
+
+/// Quotes a docstring may be delimited with.
+const QUOTES: [&str; 2] = ["\"\"\"", "'''"];
+
+/// Kind of a docstring section translated into block tags.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum SectionKind {
+    /// `Args:` or `Parameters`, one `@param` tag per entry.
+    Params,
+    /// `Returns:`, a single `@returns` tag.
+    Returns,
+    /// `Raises:`, one `@throws` tag per entry.
+    Raises,
+}
+
+/// Parses a Python docstring into a [`DocComment`].
+///
+/// `input` may be the bare docstring text or include the enclosing `"""` or `'''` quotes.
+/// The following sections are translated into block tags, written either in the Google style,
+/// i.e. `Args:`, or in the `NumPy` style, i.e. `Parameters` underlined with dashes:
+///
+/// - every entry of `Args:`, `Arguments:` or `Parameters` becomes a `@param` tag
+///   with the parameter name followed by its description;
+/// - `Returns:` becomes a single `@returns` tag;
+/// - every entry of `Raises:` becomes a `@throws` tag
+///   with the exception type followed by its description.
+///
+/// Types of the parameters, as in `name (int): description` or `name : int`, are left out.
+/// Any other text, including other sections, goes to the description.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::style::python::parse_python_docstring;
+///
+/// let doc = parse_python_docstring(r#""""Finds the user.
+///
+///     Args:
+///         id (int): The user's identifier.
+///
+///     Returns:
+///         The found user.
+///
+///     Raises:
+///         KeyError: If there's no such user.
+///     """"#).unwrap();
+///
+/// assert_eq!(doc.tag_names().collect::<Vec<_>>(), vec!["param", "returns", "throws"]);
+/// assert_eq!(doc.param_names(), vec!["id"]);
+/// ```
+///
+/// # Errors
+///
+/// If `input` starts with docstring quotes that aren't closed, an error is returned.
+pub fn parse_python_docstring(input: &str) -> Result<DocComment<'_>, Error> {
+    let text = strip_quotes(input)?;
+    let lines: Vec<_> = text.split_inclusive('\n').collect();
+
+    let mut description = vec![];
+    let mut block_tags = vec![];
+    let mut index = 0;
+    while index < lines.len() {
+        let Some((kind, is_numpy)) = section_header(&lines, index) else {
+            let line = lines[index].trim_start_matches([' ', '\t']);
+            description.push(BodyItem::TextSegment(if line.trim().is_empty() {
+                "\n"
+            } else {
+                line
+            }));
+            index += 1;
+            continue;
+        };
+
+        let header_indent = indent(lines[index]);
+        index += if is_numpy { 2 } else { 1 };
+        let start = index;
+        while index < lines.len() {
+            let line = lines[index];
+            let ends_section = if is_numpy {
+                is_underlined(&lines, index) || indent(line) < header_indent
+            } else {
+                indent(line) <= header_indent
+            };
+            if !line.trim().is_empty() && ends_section {
+                break;
+            }
+            index += 1;
+        }
+
+        for body_items in section_entries(&lines[start..index], kind, is_numpy) {
+            let name = match kind {
+                SectionKind::Params => "param",
+                SectionKind::Returns => "returns",
+                SectionKind::Raises => "throws",
+            };
+            block_tags.push(BlockTag {
+                name,
+                position: block_tags.len(),
+                body_items,
+            });
+        }
+    }
+
+    while description
+        .last()
+        .is_some_and(|item| *item == BodyItem::TextSegment("\n"))
+    {
+        description.pop();
+    }
+    Ok(DocComment {
+        description: Some(Description {
+            body_items: description,
+        })
+        .filter(|description| !description.body_items.is_empty()),
+        block_tags,
+    })
+}
+
+/// Returns the text between the docstring quotes, or `input` itself if it isn't quoted.
+fn strip_quotes(input: &str) -> Result<&str, Error> {
+    let trimmed = input.trim();
+    for quotes in QUOTES {
+        if let Some(rest) = trimmed.strip_prefix(quotes) {
+            return rest.strip_suffix(quotes).ok_or_else(|| {
+                Error::ParseError(format!("unterminated docstring, missing {quotes}"))
+            });
+        }
+    }
+    Ok(input)
+}
+
+/// Returns the kind of the section starting at `lines[index]`, if any,
+/// along with whether it's a `NumPy` style section, i.e. its header is underlined.
+fn section_header(lines: &[&str], index: usize) -> Option<(SectionKind, bool)> {
+    let header = lines[index].trim();
+    if is_underlined(lines, index) {
+        return Some((
+            match header {
+                "Parameters" | "Args" | "Arguments" => SectionKind::Params,
+                "Returns" => SectionKind::Returns,
+                "Raises" => SectionKind::Raises,
+                _ => return None,
+            },
+            true,
+        ));
+    }
+
+    let kind = match header {
+        "Args:" | "Arguments:" | "Parameters:" => SectionKind::Params,
+        "Returns:" | "Return:" => SectionKind::Returns,
+        "Raises:" => SectionKind::Raises,
+        _ => return None,
+    };
+    Some((kind, false))
+}
+
+/// Returns `true` if `lines[index]` is the header of a `NumPy` style section of any kind,
+/// i.e. it's followed by a line of dashes.
+fn is_underlined(lines: &[&str], index: usize) -> bool {
+    !lines[index].trim().is_empty()
+        && lines.get(index + 1).is_some_and(|line| {
+            let line = line.trim();
+            !line.is_empty() && line.chars().all(|ch| ch == '-')
+        })
+}
+
+/// Splits the lines of a section into the bodies of its block tags.
+fn section_entries<'a>(
+    lines: &[&'a str],
+    kind: SectionKind,
+    is_numpy: bool,
+) -> Vec<Vec<BodyItem<'a>>> {
+    let lines: Vec<_> = lines
+        .iter()
+        .copied()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    let Some(entry_indent) = lines.first().map(|line| indent(line)) else {
+        return vec![];
+    };
+    if kind == SectionKind::Returns {
+        return vec![lines
+            .iter()
+            .map(|line| BodyItem::TextSegment(line.trim_start()))
+            .collect()];
+    }
+
+    let mut entries: Vec<Vec<BodyItem<'a>>> = vec![];
+    for line in lines {
+        match entries.last_mut() {
+            Some(body_items) if indent(line) > entry_indent => {
+                body_items.push(BodyItem::TextSegment(line.trim_start()));
+            }
+            _ => entries.push(entry_header(line, is_numpy)),
+        }
+    }
+    entries
+}
+
+/// Parses the first line of a section entry, i.e. `name (type): description` in the Google
+/// style or `name : type` in the `NumPy` style, into the name followed by the description.
+fn entry_header(line: &str, is_numpy: bool) -> Vec<BodyItem<'_>> {
+    let (name, description) = line
+        .split_once(':')
+        .map_or((line, None), |(name, rest)| (name, Some(rest)));
+    let name = name
+        .trim()
+        .split(|ch: char| ch == '(' || ch.is_whitespace())
+        .next()
+        .unwrap_or_default();
+
+    let mut body_items = vec![BodyItem::TextSegment(name)];
+    match description.map(str::trim_start) {
+        Some(description) if !is_numpy && !description.is_empty() => {
+            body_items.push(BodyItem::TextSegment(" "));
+            body_items.push(BodyItem::TextSegment(description));
+        }
+        _ => body_items.push(BodyItem::TextSegment("\n")),
+    }
+    body_items
+}
+
+/// Returns the width of the indentation of `line`.
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start_matches([' ', '\t']).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_python_docstring_google() {
+        assert_eq!(
+            parse_python_docstring(
+                "Finds the user.
+
+                Details.
+
+                Args:
+                    id (int): The user's
+                        identifier.
+                    strict: Whether to fail.
+                    verbose
+
+                Returns:
+                    The found user.
+
+                Raises:
+                    KeyError: If there's no such user.
+                "
+            ),
+            Ok(DocComment {
+                description: Some(Description {
+                    body_items: vec![
+                        BodyItem::TextSegment("Finds the user.\n"),
+                        BodyItem::TextSegment("\n"),
+                        BodyItem::TextSegment("Details.\n"),
+                    ]
+                }),
+                block_tags: vec![
+                    BlockTag {
+                        name: "param",
+                        position: 0,
+                        body_items: vec![
+                            BodyItem::TextSegment("id"),
+                            BodyItem::TextSegment(" "),
+                            BodyItem::TextSegment("The user's\n"),
+                            BodyItem::TextSegment("identifier.\n"),
+                        ]
+                    },
+                    BlockTag {
+                        name: "param",
+                        position: 1,
+                        body_items: vec![
+                            BodyItem::TextSegment("strict"),
+                            BodyItem::TextSegment(" "),
+                            BodyItem::TextSegment("Whether to fail.\n"),
+                        ]
+                    },
+                    BlockTag {
+                        name: "param",
+                        position: 2,
+                        body_items: vec![
+                            BodyItem::TextSegment("verbose"),
+                            BodyItem::TextSegment("\n"),
+                        ]
+                    },
+                    BlockTag {
+                        name: "returns",
+                        position: 3,
+                        body_items: vec![BodyItem::TextSegment("The found user.\n")]
+                    },
+                    BlockTag {
+                        name: "throws",
+                        position: 4,
+                        body_items: vec![
+                            BodyItem::TextSegment("KeyError"),
+                            BodyItem::TextSegment(" "),
+                            BodyItem::TextSegment("If there's no such user.\n"),
+                        ]
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_python_docstring_numpy() {
+        let doc = parse_python_docstring(
+            r"'''Finds the user.
+
+            Parameters
+            ----------
+            id : int
+                The user's identifier.
+
+            Returns
+            -------
+            User
+                The found user.
+            '''",
+        )
+        .unwrap();
+
+        assert_eq!(
+            doc.description,
+            Some(Description {
+                body_items: vec![BodyItem::TextSegment("Finds the user.\n")]
+            })
+        );
+        assert_eq!(
+            doc.block_tags[0].body_items,
+            vec![
+                BodyItem::TextSegment("id"),
+                BodyItem::TextSegment("\n"),
+                BodyItem::TextSegment("The user's identifier.\n"),
+            ]
+        );
+        assert_eq!(
+            doc.block_tags[1].body_items,
+            vec![
+                BodyItem::TextSegment("User\n"),
+                BodyItem::TextSegment("The found user.\n"),
+            ]
+        );
+        assert_eq!(doc.block_tags.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_python_docstring_numpy_other_sections() {
+        let doc = parse_python_docstring(
+            r"'''Finds the user.
+
+            Parameters
+            ----------
+            id : int
+                The user's identifier.
+
+            Notes
+            -----
+            Some notes.
+
+            Examples
+            --------
+            >>> find(1)
+            '''",
+        )
+        .unwrap();
+
+        assert_eq!(doc.param_names(), vec!["id"]);
+        assert_eq!(doc.block_tags.len(), 1);
+        assert_eq!(
+            doc.description,
+            Some(Description {
+                body_items: vec![
+                    BodyItem::TextSegment("Finds the user.\n"),
+                    BodyItem::TextSegment("\n"),
+                    BodyItem::TextSegment("Notes\n"),
+                    BodyItem::TextSegment("-----\n"),
+                    BodyItem::TextSegment("Some notes.\n"),
+                    BodyItem::TextSegment("\n"),
+                    BodyItem::TextSegment("Examples\n"),
+                    BodyItem::TextSegment("--------\n"),
+                    BodyItem::TextSegment(">>> find(1)\n"),
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_python_docstring_quotes() {
+        assert_eq!(parse_python_docstring(""), Ok(DocComment::default()));
+        assert_eq!(
+            parse_python_docstring(r#""""Summary.""""#)
+                .unwrap()
+                .description,
+            Some(Description {
+                body_items: vec![BodyItem::TextSegment("Summary.")]
+            })
+        );
+        assert!(parse_python_docstring(r#""""Summary."#).is_err());
+    }
+}
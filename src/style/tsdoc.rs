@@ -0,0 +1,116 @@
+//! Support for [TSDoc](https://tsdoc.org/), the doc comment standard for TypeScript.
+
+use crate::ast::DocComment;
+use crate::config::{ParseConfig, TagNameSyntax};
+use crate::error::Error;
+use crate::parse_with_config;
+
+/// Parses `input` as a TSDoc comment.
+///
+/// The following deviations from the base grammar are applied:
+///
+/// - tag names may contain `-` and start with `_`, e.g. `@_internal`,
+///   see [`TagNameSyntax::Extended`];
+/// - single-line `{@link Target | label}` inline tags become
+///   [`BodyItem::Link`](crate::ast::BodyItem::Link) items, see [`ParseConfig::parse_links`].
+///
+/// Modifier tags like `@public` or `@internal` are regular block tags without a body.
+/// The set of tag names isn't restricted.
+///
+/// # Examples
+///
+/// ```
+/// use doctor::ast::BodyItem;
+/// use doctor::style::tsdoc::parse_tsdoc;
+///
+/// let doc = parse_tsdoc("/** Uses {@link Foo | the foo}.\n * @_private */").unwrap();
+///
+/// assert_eq!(
+///     doc.description.unwrap().body_items[1],
+///     BodyItem::Link { target: "Foo", label: Some("the foo") }
+/// );
+/// assert_eq!(doc.block_tags[0].name, "_private");
+/// ```
+///
+/// # Errors
+///
+/// If `input` is not a valid doc comment, an error explaining where the parsing failed is returned.
+pub fn parse_tsdoc(input: &str) -> Result<DocComment<'_>, Error> {
+    parse_with_config(input, tsdoc_config())
+}
+
+/// Returns the [`ParseConfig`] used by [`parse_tsdoc`].
+#[must_use]
+pub fn tsdoc_config() -> ParseConfig {
+    ParseConfig {
+        tag_name_syntax: TagNameSyntax::Extended,
+        parse_links: true,
+        ..ParseConfig::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{BlockTag, BodyItem, Description, InlineTag};
+
+    #[test]
+    fn test_parse_tsdoc() {
+        assert_eq!(
+            parse_tsdoc(
+                r"/**
+                * See {@link Foo}, {@link https://example.com | the docs} or {@link Bar
+                * baz}.
+                *
+                * @public
+                * @my-tag {@link Qux label}
+                */"
+            ),
+            Ok(DocComment {
+                description: Some(Description {
+                    body_items: vec![
+                        BodyItem::TextSegment("See "),
+                        BodyItem::Link {
+                            target: "Foo",
+                            label: None
+                        },
+                        BodyItem::TextSegment(", "),
+                        BodyItem::Link {
+                            target: "https://example.com",
+                            label: Some("the docs")
+                        },
+                        BodyItem::TextSegment("or "),
+                        BodyItem::InlineTag(InlineTag {
+                            name: "link",
+                            body_lines: vec!["Bar\n", "baz"]
+                        }),
+                        BodyItem::TextSegment(".\n"),
+                        BodyItem::TextSegment("\n"),
+                    ]
+                }),
+                block_tags: vec![
+                    BlockTag {
+                        name: "public",
+                        position: 0,
+                        body_items: vec![]
+                    },
+                    BlockTag {
+                        name: "my-tag",
+                        position: 1,
+                        body_items: vec![
+                            BodyItem::Link {
+                                target: "Qux",
+                                label: Some("label")
+                            },
+                            BodyItem::TextSegment("\n"),
+                        ]
+                    },
+                ]
+            })
+        );
+        assert_eq!(
+            crate::parse("/** @my-tag */").unwrap().block_tags[0].name,
+            "my"
+        );
+    }
+}
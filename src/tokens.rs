@@ -0,0 +1,176 @@
+//! [`ToTokens`] implementations allowing AST nodes to be interpolated in `quote!` invocations.
+//!
+//! Every node expands to an expression constructing its owned counterpart,
+//! e.g. a `DocComment` expands to a `::doctor::ast::OwnedDocComment { … }` expression.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::ast::{BlockTag, BodyItem, Description, DocComment, InlineTag};
+
+impl ToTokens for DocComment<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let description = self.description.as_ref().map_or_else(
+            || quote! { ::core::option::Option::None },
+            |description| quote! { ::core::option::Option::Some(#description) },
+        );
+        let block_tags = &self.block_tags;
+        tokens.extend(quote! {
+            ::doctor::ast::OwnedDocComment {
+                description: #description,
+                block_tags: ::std::vec![#(#block_tags),*],
+            }
+        });
+    }
+}
+
+impl ToTokens for Description<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let body_items = &self.body_items;
+        tokens.extend(quote! {
+            ::doctor::ast::OwnedDescription {
+                body_items: ::std::vec![#(#body_items),*],
+            }
+        });
+    }
+}
+
+impl ToTokens for BlockTag<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.name;
+        let position = self.position;
+        let body_items = &self.body_items;
+        tokens.extend(quote! {
+            ::doctor::ast::OwnedBlockTag {
+                name: ::std::string::String::from(#name),
+                position: #position,
+                body_items: ::std::vec![#(#body_items),*],
+            }
+        });
+    }
+}
+
+impl ToTokens for BodyItem<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            BodyItem::TextSegment(s) => quote! {
+                ::doctor::ast::OwnedBodyItem::TextSegment(::std::string::String::from(#s))
+            },
+            BodyItem::InlineTag(tag) => quote! {
+                ::doctor::ast::OwnedBodyItem::InlineTag(#tag)
+            },
+            BodyItem::CodeSpan(code) => quote! {
+                ::doctor::ast::OwnedBodyItem::CodeSpan(::std::string::String::from(#code))
+            },
+            BodyItem::Link { target, label } => {
+                let label = label.map_or_else(
+                    || quote! { ::core::option::Option::None },
+                    |label| {
+                        quote! { ::core::option::Option::Some(::std::string::String::from(#label)) }
+                    },
+                );
+                quote! {
+                    ::doctor::ast::OwnedBodyItem::Link {
+                        target: ::std::string::String::from(#target),
+                        label: #label,
+                    }
+                }
+            }
+            BodyItem::InheritDoc => quote! {
+                ::doctor::ast::OwnedBodyItem::InheritDoc
+            },
+            BodyItem::CodeBlock { language, lines } => {
+                let language = language.map_or_else(
+                    || quote! { ::core::option::Option::None },
+                    |language| {
+                        quote! { ::core::option::Option::Some(::std::string::String::from(#language)) }
+                    },
+                );
+                quote! {
+                    ::doctor::ast::OwnedBodyItem::CodeBlock {
+                        language: #language,
+                        lines: ::std::vec![#(::std::string::String::from(#lines)),*],
+                    }
+                }
+            }
+        });
+    }
+}
+
+impl ToTokens for InlineTag<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.name;
+        let body_lines = &self.body_lines;
+        tokens.extend(quote! {
+            ::doctor::ast::OwnedInlineTag {
+                name: ::std::string::String::from(#name),
+                body_lines: ::std::vec![#(::std::string::String::from(#body_lines)),*],
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_doc_comment_to_tokens() {
+        let doc = parse("/** Description {@link Foo}\n * @param*/").unwrap();
+
+        assert_eq!(
+            quote! { #doc }.to_string(),
+            quote! {
+                ::doctor::ast::OwnedDocComment {
+                    description: ::core::option::Option::Some(::doctor::ast::OwnedDescription {
+                        body_items: ::std::vec![
+                            ::doctor::ast::OwnedBodyItem::TextSegment(::std::string::String::from("Description ")),
+                            ::doctor::ast::OwnedBodyItem::InlineTag(::doctor::ast::OwnedInlineTag {
+                                name: ::std::string::String::from("link"),
+                                body_lines: ::std::vec![::std::string::String::from("Foo")],
+                            }),
+                            ::doctor::ast::OwnedBodyItem::TextSegment(::std::string::String::from("\n"))
+                        ],
+                    }),
+                    block_tags: ::std::vec![::doctor::ast::OwnedBlockTag {
+                        name: ::std::string::String::from("param"),
+                        position: 0usize,
+                        body_items: ::std::vec![],
+                    }],
+                }
+            }
+            .to_string()
+        );
+        let empty_doc = DocComment::default();
+        assert_eq!(
+            quote! { #empty_doc }.to_string(),
+            quote! {
+                ::doctor::ast::OwnedDocComment {
+                    description: ::core::option::Option::None,
+                    block_tags: ::std::vec![],
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_code_block_to_tokens() {
+        let item = BodyItem::CodeBlock {
+            language: Some("rust"),
+            lines: vec!["foo();\n"],
+        };
+
+        assert_eq!(
+            quote! { #item }.to_string(),
+            quote! {
+                ::doctor::ast::OwnedBodyItem::CodeBlock {
+                    language: ::core::option::Option::Some(::std::string::String::from("rust")),
+                    lines: ::std::vec![::std::string::String::from("foo();\n")],
+                }
+            }
+            .to_string()
+        );
+    }
+}
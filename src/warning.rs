@@ -0,0 +1,120 @@
+//! Non-fatal issues found in successfully parsed doc comments.
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::ast::{BodyItem, DocComment};
+
+/// A non-fatal issue found in a doc comment.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Warning {
+    pub kind: WarningKind,
+    /// Human-readable description of the issue.
+    pub message: String,
+}
+
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum WarningKind {
+    /// A block tag has no body.
+    EmptyTagBody,
+    /// Text looks like an inline tag, e.g. an escaped `\{\@link Foo\}`, but wasn't parsed as one.
+    UnrecognisedInlineTagPattern,
+    /// The comment has no description.
+    MissingDescription,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Returns the warnings for `doc`, in document order.
+pub(crate) fn check(doc: &DocComment<'_>) -> Vec<Warning> {
+    let mut warnings = vec![];
+    match &doc.description {
+        Some(description) if !is_blank(&description.body_items) => {
+            check_text(&description.body_items, &mut warnings);
+        }
+        _ => warnings.push(Warning {
+            kind: WarningKind::MissingDescription,
+            message: "the comment has no description".to_owned(),
+        }),
+    }
+    for tag in &doc.block_tags {
+        if is_blank(&tag.body_items) {
+            warnings.push(Warning {
+                kind: WarningKind::EmptyTagBody,
+                message: format!("`@{}` tag has an empty body", tag.name),
+            });
+        }
+        check_text(&tag.body_items, &mut warnings);
+    }
+    warnings
+}
+
+fn is_blank(body_items: &[BodyItem<'_>]) -> bool {
+    body_items
+        .iter()
+        .all(|item| matches!(item, BodyItem::TextSegment(s) if s.trim().is_empty()))
+}
+
+fn check_text(body_items: &[BodyItem<'_>], warnings: &mut Vec<Warning>) {
+    for item in body_items {
+        if let BodyItem::TextSegment(s) = item {
+            // An escaped `\{@name` is split into text ending with `{` and a block tag,
+            // so it has to be written as `\{\@name`.
+            if s.contains("{@") || s.contains("{\\@") || s.trim_end().ends_with('{') {
+                warnings.push(Warning {
+                    kind: WarningKind::UnrecognisedInlineTagPattern,
+                    message: format!("`{}` looks like an inline tag but is plain text", s.trim()),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::Description;
+    use crate::parse;
+
+    fn kinds(input: &str) -> Vec<WarningKind> {
+        check(&parse(input).unwrap())
+            .into_iter()
+            .map(|warning| warning.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(kinds("/** Description.\n * @param foo the foo */"), vec![]);
+        assert_eq!(
+            kinds("/** @param foo\n * @returns */"),
+            vec![WarningKind::MissingDescription, WarningKind::EmptyTagBody]
+        );
+        assert_eq!(
+            check(&DocComment::from(Description::from_text(" \n"))),
+            vec![Warning {
+                kind: WarningKind::MissingDescription,
+                message: "the comment has no description".to_owned(),
+            }]
+        );
+        assert_eq!(
+            kinds("/** See \\{\\@link Foo\\}. */"),
+            vec![WarningKind::UnrecognisedInlineTagPattern]
+        );
+        assert_eq!(
+            kinds("/** Description.\n * @see \\{@link Bar\\} */"),
+            vec![WarningKind::UnrecognisedInlineTagPattern]
+        );
+        assert_eq!(
+            check(&parse("/** Description.\n * @returns */").unwrap()),
+            vec![Warning {
+                kind: WarningKind::EmptyTagBody,
+                message: "`@returns` tag has an empty body".to_owned(),
+            }]
+        );
+    }
+}
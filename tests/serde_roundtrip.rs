@@ -0,0 +1,56 @@
+#![cfg(feature = "serde")]
+
+use doctor::ast::OwnedDocComment;
+use doctor::config::ParseConfig;
+use doctor::{parse, parse_with_config};
+
+const INPUTS: [&str; 10] = [
+    "/***/",
+    "/** Description. */",
+    "/**\n * Multi-line\n * description.\n */",
+    "/** Description with {@link Foo} and {@code bar}. */",
+    "/** @param foo */",
+    "/**\n * @param foo the foo\n * @returns the result\n */",
+    "/** {@link multi\n * line} */",
+    "/**\n * Description.\n *\n * @throws Error with {@inlineTag}\n * @since 1.0\n */",
+    "/** Escaped \\{braces\\} */",
+    "/**\n * Unicode: héllo, wörld 🩺.\n * @author Jöhn\n */",
+];
+
+fn docs() -> Vec<OwnedDocComment> {
+    let config = ParseConfig {
+        parse_code_spans: true,
+        parse_code_blocks: true,
+        parse_links: true,
+        ..ParseConfig::default()
+    };
+
+    INPUTS
+        .iter()
+        .map(|input| OwnedDocComment::from(&parse(input).unwrap()))
+        .chain(Some(OwnedDocComment::from(
+            &parse_with_config(
+                "/**\n * See {@link Foo the foo}, {@code bar}.\n * ```rust\n * baz();\n * ```\n */",
+                config,
+            )
+            .unwrap(),
+        )))
+        .collect()
+}
+
+#[test]
+fn test_serde_json_round_trip() {
+    for doc in docs() {
+        let json = serde_json::to_string(&doc).unwrap();
+        assert_eq!(serde_json::from_str::<OwnedDocComment>(&json).unwrap(), doc);
+    }
+}
+
+#[test]
+fn test_serde_json_schema_is_stable() {
+    let json = serde_json::to_string_pretty(&docs()).unwrap();
+    assert_eq!(
+        json,
+        include_str!("fixtures/serde_roundtrip.json").trim_end()
+    );
+}